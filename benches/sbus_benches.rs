@@ -1,8 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use embedded_io_adapters::std::FromStd;
 use sbus_rs::{
-    pack_channels, SbusPacket, SbusParser, CHANNEL_COUNT, CHANNEL_MAX, SBUS_FOOTER,
-    SBUS_FRAME_LENGTH, SBUS_HEADER,
+    pack_channel_data, pack_channels, unpack_channel_data, SbusPacket, SbusParser, CHANNEL_COUNT,
+    CHANNEL_MAX, SBUS_FOOTER, SBUS_FRAME_LENGTH, SBUS_HEADER,
 };
 use std::io::Cursor;
 
@@ -72,6 +72,21 @@ fn bench_sync_frame_parsing(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_sync_channel_unpacking(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sync/channel_unpacking");
+
+    for (name, channels) in SCENARIOS {
+        let data = pack_channel_data(channels);
+        group.bench_with_input(
+            BenchmarkId::new("sync/unpack_channel_data", name),
+            &data,
+            |b, data| b.iter(|| black_box(unpack_channel_data(black_box(data)))),
+        );
+    }
+
+    group.finish();
+}
+
 fn bench_sync_streaming_parser(c: &mut Criterion) {
     let mut group = c.benchmark_group("sync/streaming_parser");
 
@@ -160,14 +175,14 @@ fn bench_async_parser(c: &mut Criterion) {
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(200);
-    targets = bench_sync_frame_parsing, bench_sync_streaming_parser, bench_sync_frame_validation
+    targets = bench_sync_frame_parsing, bench_sync_channel_unpacking, bench_sync_streaming_parser, bench_sync_frame_validation
 }
 
 #[cfg(feature = "async")]
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(200);
-    targets = bench_sync_frame_parsing, bench_sync_streaming_parser, bench_sync_frame_validation, bench_async_parser
+    targets = bench_sync_frame_parsing, bench_sync_channel_unpacking, bench_sync_streaming_parser, bench_sync_frame_validation, bench_async_parser
 }
 
 criterion_main!(benches);
@@ -0,0 +1,20 @@
+//! Serial port setup shared by the examples
+use serialport::{DataBits, Parity, SerialPort, StopBits};
+use std::io;
+use std::time::Duration;
+
+/// SBUS line rate, which is not a standard termios speed
+const SBUS_BAUD: u32 = 100_000;
+
+/// Opens `device` configured for SBUS: 100000 baud, 8 data bits, even parity and
+/// 2 stop bits
+///
+/// Reads and writes time out after `timeout` with `io::ErrorKind::TimedOut`.
+pub fn open_port(device: &str, timeout: Duration) -> io::Result<Box<dyn SerialPort>> {
+    Ok(serialport::new(device, SBUS_BAUD)
+        .data_bits(DataBits::Eight)
+        .parity(Parity::Even)
+        .stop_bits(StopBits::Two)
+        .timeout(timeout)
+        .open()?)
+}
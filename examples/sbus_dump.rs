@@ -0,0 +1,101 @@
+//! Live decode of an SBUS stream from a serial port or a capture file
+//!
+//! ```text
+//! cargo run --example sbus_dump --features std -- --port /dev/ttyUSB0 [--us] [--invert] [--rate HZ]
+//! cargo run --example sbus_dump --features std -- --file capture.bin
+//! ```
+//!
+//! The port is configured for SBUS (100000 baud, 8E2) through the `serialport`
+//! crate.
+mod common;
+
+use common::open_port;
+use sbus_rs::{ChannelUnit, Dumper};
+use std::fs::File;
+use std::io::{self, Read};
+use std::process::exit;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "usage: sbus_dump (--port DEVICE | --file PATH) [--us] [--invert] [--rate HZ]";
+
+enum Source {
+    Port(String),
+    File(String),
+}
+
+struct Args {
+    source: Source,
+    unit: ChannelUnit,
+    invert: bool,
+    rate: f32,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut source = None;
+    let mut unit = ChannelUnit::Ticks;
+    let mut invert = false;
+    let mut rate = 10.0;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--port" => source = Some(Source::Port(value()?)),
+            "--file" => source = Some(Source::File(value()?)),
+            "--us" => unit = ChannelUnit::Microseconds,
+            "--invert" => invert = true,
+            "--rate" => {
+                rate = value()?
+                    .parse()
+                    .ok()
+                    .filter(|&r: &f32| r > 0.0)
+                    .ok_or("--rate expects a positive number")?
+            }
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    Ok(Args {
+        source: source.ok_or("either --port or --file is required")?,
+        unit,
+        invert,
+        rate,
+    })
+}
+
+fn main() -> io::Result<()> {
+    let args = parse_args().unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        exit(2);
+    });
+
+    let mut input: Box<dyn Read> = match &args.source {
+        Source::Port(device) => Box::new(open_port(device, Duration::from_secs(1))?),
+        Source::File(path) => Box::new(File::open(path)?),
+    };
+    let interval = Duration::from_secs_f32(1.0 / args.rate);
+    let mut dumper = Dumper::new(args.unit, args.invert, interval);
+    let mut stdout = io::stdout().lock();
+
+    let mut buf = [0u8; 256];
+    loop {
+        let n = match input.read(&mut buf) {
+            // An idle port times out; keep waiting for the transmitter
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => continue,
+            result => result?,
+        };
+        if n == 0 {
+            break;
+        }
+        dumper.feed(&buf[..n], Instant::now(), &mut stdout)?;
+    }
+
+    let stats = dumper.stats();
+    eprintln!(
+        "{} frames, {} sync losses, LQ {}%",
+        stats.frames,
+        stats.sync_losses,
+        stats.link_quality()
+    );
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//! Transmits scripted SBUS frames to a serial port or stdout
+//!
+//! ```text
+//! cargo run --example sbus_gen --features std -- --channels 1500,1500,1000,1500 --port /dev/ttyUSB0
+//! cargo run --example sbus_gen --features std -- --sweep 200 --fast > frames.bin
+//! cargo run --example sbus_gen --features std -- --replay capture.bin --failsafe
+//! ```
+//!
+//! Channel lists are in microseconds and accept `VALUExCOUNT` repetitions. The port
+//! is configured for SBUS (100000 baud, 8E2) through the `serialport` crate.
+mod common;
+
+use common::open_port;
+use sbus_rs::{
+    scan_reader, ChannelUnit, Flags, FrameGenerator, FramePacer, Pattern, SbusPacket,
+    SBUS_FRAME_PERIOD_FAST, SBUS_FRAME_PERIOD_NORMAL,
+};
+use std::fs::File;
+use std::io::{self, Write};
+use std::process::exit;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "usage: sbus_gen (--channels LIST | --sweep FRAMES | --replay PATH) \
+                     [--port DEVICE] [--fast] [--failsafe] [--frame-lost] [--count N]";
+
+struct Args {
+    pattern: Pattern,
+    port: Option<String>,
+    fast: bool,
+    flags: Flags,
+    count: Option<u64>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut pattern = None;
+    let mut port = None;
+    let mut fast = false;
+    let mut flags = Flags::default();
+    let mut count = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--channels" => {
+                let packet = SbusPacket::from_str_channels(&value()?, ChannelUnit::Microseconds)
+                    .map_err(|e| e.to_string())?;
+                pattern = Some(Pattern::Fixed(packet));
+            }
+            "--sweep" => {
+                let period = value()?
+                    .parse()
+                    .map_err(|_| "--sweep expects a frame count")?;
+                pattern = Some(Pattern::Sweep { period });
+            }
+            "--replay" => {
+                let file = File::open(value()?).map_err(|e| e.to_string())?;
+                let packets = scan_reader(file)
+                    .map(|(_, packet)| packet.map_err(|e| format!("{:?}", e)))
+                    .collect::<Result<_, _>>()?;
+                pattern = Some(Pattern::Replay(packets));
+            }
+            "--port" => port = Some(value()?),
+            "--fast" => fast = true,
+            "--failsafe" => flags.failsafe = true,
+            "--frame-lost" => flags.frame_lost = true,
+            "--count" => count = Some(value()?.parse().map_err(|_| "--count expects a number")?),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    Ok(Args {
+        pattern: pattern.ok_or("one of --channels, --sweep or --replay is required")?,
+        port,
+        fast,
+        flags,
+        count,
+    })
+}
+
+fn main() -> io::Result<()> {
+    let args = parse_args().unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        exit(2);
+    });
+
+    let mut output: Box<dyn Write> = match &args.port {
+        Some(device) => Box::new(open_port(device, Duration::from_secs(1))?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let period = if args.fast {
+        SBUS_FRAME_PERIOD_FAST
+    } else {
+        SBUS_FRAME_PERIOD_NORMAL
+    };
+    let mut pacer = FramePacer::new(period, Instant::now());
+    let generator = FrameGenerator::new(args.pattern, args.flags);
+
+    for frame in generator.take(args.count.unwrap_or(u64::MAX) as usize) {
+        let now = Instant::now();
+        let deadline = pacer.next_deadline(now);
+        std::thread::sleep(deadline.saturating_duration_since(now));
+        output.write_all(&frame)?;
+        output.flush()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! Arm switch detection with hysteresis
+use crate::{conversion::ticks_to_us, packet::SbusPacket};
+
+/// Arming state read from a switch channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArmState {
+    Armed,
+    Disarmed,
+    /// The switch is between the thresholds, e.g. while it moves; the previous
+    /// state should be kept
+    Indeterminate,
+}
+
+/// Arm switch on one channel, with separate arm and disarm thresholds in µs
+///
+/// ExpressLRS conventionally arms on channel 5 (index 4) above 1800 µs and disarms
+/// below 1200 µs. The gap between the thresholds provides hysteresis: a value in
+/// between is [`ArmState::Indeterminate`] instead of flipping the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArmSwitch {
+    channel: usize,
+    arm_threshold: u16,
+    disarm_threshold: u16,
+}
+
+impl ArmSwitch {
+    pub fn new(channel: usize, arm_threshold: u16, disarm_threshold: u16) -> Self {
+        Self {
+            channel,
+            arm_threshold,
+            disarm_threshold,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the switch channel is not below 16.
+    pub fn check(&self, packet: &SbusPacket) -> ArmState {
+        if packet.is_arm_switch_high(self.channel, self.arm_threshold) {
+            ArmState::Armed
+        } else if packet.is_arm_switch_low(self.channel, self.disarm_threshold) {
+            ArmState::Disarmed
+        } else {
+            ArmState::Indeterminate
+        }
+    }
+}
+
+impl SbusPacket {
+    /// Returns `true` if `arm_channel` is at or above `threshold_high` µs
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arm_channel` is not below 16.
+    pub fn is_arm_switch_high(&self, arm_channel: usize, threshold_high: u16) -> bool {
+        ticks_to_us(self.channels[arm_channel]) >= threshold_high
+    }
+
+    /// Returns `true` if `arm_channel` is at or below `threshold_low` µs
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arm_channel` is not below 16.
+    pub fn is_arm_switch_low(&self, arm_channel: usize, threshold_low: u16) -> bool {
+        ticks_to_us(self.channels[arm_channel]) <= threshold_low
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::us_to_ticks;
+
+    fn with_switch(us: u16) -> SbusPacket {
+        let mut packet = SbusPacket::default();
+        packet.channels[4] = us_to_ticks(us);
+        packet
+    }
+
+    #[test]
+    fn test_arm_switch_states() {
+        let switch = ArmSwitch::new(4, 1800, 1200);
+        assert_eq!(switch.check(&with_switch(2000)), ArmState::Armed);
+        assert_eq!(switch.check(&with_switch(1000)), ArmState::Disarmed);
+        assert_eq!(switch.check(&with_switch(1500)), ArmState::Indeterminate);
+
+        assert!(with_switch(2000).is_arm_switch_high(4, 1800));
+        assert!(!with_switch(2000).is_arm_switch_low(4, 1200));
+        assert!(with_switch(1000).is_arm_switch_low(4, 1200));
+    }
+
+    #[test]
+    fn test_arm_switch_hysteresis() {
+        let switch = ArmSwitch::new(4, 1800, 1200);
+        let mut state = ArmState::Disarmed;
+        let mut states = [ArmState::Disarmed; 7];
+        // Move up to armed and back down: the state only changes past a threshold
+        for (i, us) in [1100, 1500, 1790, 1850, 1500, 1210, 1150]
+            .into_iter()
+            .enumerate()
+        {
+            match switch.check(&with_switch(us)) {
+                ArmState::Indeterminate => {}
+                new_state => state = new_state,
+            }
+            states[i] = state;
+        }
+        use ArmState::*;
+        assert_eq!(
+            states,
+            [Disarmed, Disarmed, Disarmed, Armed, Armed, Armed, Disarmed]
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Bounded single-producer single-consumer queue of decoded packets
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use heapless::spsc::{Consumer, Producer, Queue};
+
+use crate::packet::SbusPacket;
+
+/// What to do with a packet pushed into a full [`SbusChannel`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PushPolicy {
+    /// Discard the oldest queued packet, e.g. for control where only recent input
+    /// matters
+    DropOldest,
+    /// Discard the pushed packet, e.g. for logging where the queued history matters
+    #[default]
+    DropNewest,
+}
+
+/// Counters shared between both halves, each written by one side only
+#[derive(Debug, Default)]
+struct Counters {
+    dropped_by_producer: AtomicUsize,
+    dropped_by_consumer: AtomicUsize,
+    evictions_requested: AtomicUsize,
+    evictions_done: AtomicUsize,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Self {
+            dropped_by_producer: AtomicUsize::new(0),
+            dropped_by_consumer: AtomicUsize::new(0),
+            evictions_requested: AtomicUsize::new(0),
+            evictions_done: AtomicUsize::new(0),
+        }
+    }
+
+    /// Increments a counter owned by the calling side, using only loads and stores
+    fn bump(counter: &AtomicUsize) {
+        counter.store(
+            counter.load(Ordering::Relaxed).wrapping_add(1),
+            Ordering::Release,
+        );
+    }
+
+    fn dropped(&self) -> usize {
+        self.dropped_by_producer
+            .load(Ordering::Acquire)
+            .wrapping_add(self.dropped_by_consumer.load(Ordering::Acquire))
+    }
+}
+
+/// Bounded queue carrying decoded packets from one context to another
+///
+/// Built on [`heapless::spsc::Queue`], so it holds at most `N - 1` packets. Use
+/// [`SbusChannel::split`] to obtain the producer and consumer halves.
+#[derive(Debug)]
+pub struct SbusChannel<const N: usize> {
+    queue: Queue<SbusPacket, N>,
+    policy: PushPolicy,
+    counters: Counters,
+}
+
+impl<const N: usize> SbusChannel<N> {
+    pub const fn new(policy: PushPolicy) -> Self {
+        Self {
+            queue: Queue::new(),
+            policy,
+            counters: Counters::new(),
+        }
+    }
+
+    /// Number of packets the queue can hold
+    pub const fn capacity(&self) -> usize {
+        N - 1
+    }
+
+    /// Total number of packets dropped on overflow
+    pub fn dropped(&self) -> usize {
+        self.counters.dropped()
+    }
+
+    /// Splits the channel into its producer and consumer halves
+    pub fn split(&mut self) -> (SbusProducer<'_, N>, SbusConsumer<'_, N>) {
+        let (producer, consumer) = self.queue.split();
+        (
+            SbusProducer {
+                inner: producer,
+                policy: self.policy,
+                pending: None,
+                counters: &self.counters,
+            },
+            SbusConsumer {
+                inner: consumer,
+                counters: &self.counters,
+            },
+        )
+    }
+}
+
+impl<const N: usize> Default for SbusChannel<N> {
+    fn default() -> Self {
+        Self::new(PushPolicy::default())
+    }
+}
+
+/// Producing half of an [`SbusChannel`]
+///
+/// Never blocks or panics, so it can be used from an interrupt handler.
+pub struct SbusProducer<'a, const N: usize> {
+    inner: Producer<'a, SbusPacket, N>,
+    policy: PushPolicy,
+    /// Newest packet waiting for the consumer to evict the oldest one
+    pending: Option<SbusPacket>,
+    counters: &'a Counters,
+}
+
+impl<const N: usize> SbusProducer<'_, N> {
+    /// Queues a packet, dropping one according to the policy if the queue is full
+    ///
+    /// The producer cannot remove packets itself, so under
+    /// [`PushPolicy::DropOldest`] it asks the consumer to discard the oldest packet
+    /// and holds the new one until there is room; it is queued by the next call to
+    /// `push` or [`SbusProducer::flush`]. Holding a newer packet replaces the one
+    /// already held, which counts as a drop.
+    pub fn push(&mut self, packet: SbusPacket) {
+        self.flush();
+        match (self.policy, self.pending) {
+            (PushPolicy::DropNewest, _) => {
+                if self.inner.enqueue(packet).is_err() {
+                    Counters::bump(&self.counters.dropped_by_producer);
+                }
+            }
+            (PushPolicy::DropOldest, Some(_)) => {
+                self.pending = Some(packet);
+                Counters::bump(&self.counters.dropped_by_producer);
+            }
+            (PushPolicy::DropOldest, None) => {
+                if let Err(packet) = self.inner.enqueue(packet) {
+                    self.pending = Some(packet);
+                    Counters::bump(&self.counters.evictions_requested);
+                }
+            }
+        }
+    }
+
+    /// Queues the held packet if the consumer made room; returns `true` if no
+    /// packet is held anymore
+    pub fn flush(&mut self) -> bool {
+        if let Some(packet) = self.pending {
+            if self.inner.enqueue(packet).is_ok() {
+                self.pending = None;
+            }
+        }
+        self.pending.is_none()
+    }
+
+    /// Total number of packets dropped on overflow
+    pub fn dropped(&self) -> usize {
+        self.counters.dropped()
+    }
+}
+
+/// Consuming half of an [`SbusChannel`]
+pub struct SbusConsumer<'a, const N: usize> {
+    inner: Consumer<'a, SbusPacket, N>,
+    counters: &'a Counters,
+}
+
+impl<const N: usize> SbusConsumer<'_, N> {
+    /// Returns the oldest queued packet
+    pub fn pop(&mut self) -> Option<SbusPacket> {
+        let requested = self.counters.evictions_requested.load(Ordering::Acquire);
+        let done = self.counters.evictions_done.load(Ordering::Relaxed);
+        if requested != done {
+            if self.inner.dequeue().is_some() {
+                Counters::bump(&self.counters.dropped_by_consumer);
+            }
+            Counters::bump(&self.counters.evictions_done);
+        }
+        self.inner.dequeue()
+    }
+
+    /// Number of queued packets
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.inner.ready()
+    }
+
+    /// Total number of packets dropped on overflow
+    pub fn dropped(&self) -> usize {
+        self.counters.dropped()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(value: u16) -> SbusPacket {
+        SbusPacket {
+            channels: [value; 16],
+            ..SbusPacket::default()
+        }
+    }
+
+    fn drain<const N: usize>(consumer: &mut SbusConsumer<'_, N>) -> heapless::Vec<u16, N> {
+        core::iter::from_fn(|| consumer.pop())
+            .map(|p| p.channels[0])
+            .collect()
+    }
+
+    #[test]
+    fn test_fifo_order() {
+        for policy in [PushPolicy::DropOldest, PushPolicy::DropNewest] {
+            let mut channel = SbusChannel::<5>::new(policy);
+            assert_eq!(channel.capacity(), 4);
+            let (mut producer, mut consumer) = channel.split();
+            for value in 0..4 {
+                producer.push(packet(value));
+            }
+            assert_eq!(consumer.len(), 4);
+            assert_eq!(drain(&mut consumer), [0, 1, 2, 3]);
+            assert!(consumer.is_empty());
+            assert_eq!(consumer.dropped(), 0);
+        }
+    }
+
+    #[test]
+    fn test_overflow_drop_newest() {
+        let mut channel = SbusChannel::<5>::new(PushPolicy::DropNewest);
+        let (mut producer, mut consumer) = channel.split();
+        for value in 0..7 {
+            producer.push(packet(value));
+        }
+        assert_eq!(producer.dropped(), 3);
+        assert_eq!(drain(&mut consumer), [0, 1, 2, 3]);
+        assert_eq!(channel.dropped(), 3);
+    }
+
+    #[test]
+    fn test_overflow_drop_oldest() {
+        let mut channel = SbusChannel::<5>::new(PushPolicy::DropOldest);
+        let (mut producer, mut consumer) = channel.split();
+        for value in 0..7 {
+            producer.push(packet(value));
+        }
+        // 0 is evicted once the consumer runs, 4 and 5 were replaced while held
+        assert_eq!(producer.dropped(), 2);
+        assert_eq!(consumer.pop().map(|p| p.channels[0]), Some(1));
+        assert!(producer.flush());
+        assert_eq!(drain(&mut consumer), [2, 3, 6]);
+        assert_eq!(consumer.dropped(), 3);
+
+        // Back to plain FIFO once drained
+        producer.push(packet(7));
+        producer.push(packet(8));
+        assert_eq!(drain(&mut consumer), [7, 8]);
+        assert_eq!(channel.dropped(), 3);
+    }
+
+    #[test]
+    fn test_drop_oldest_keeps_latest() {
+        let mut channel = SbusChannel::<3>::new(PushPolicy::DropOldest);
+        let (mut producer, mut consumer) = channel.split();
+        for round in 0..10 {
+            for value in 0..5 {
+                producer.push(packet(round * 10 + value));
+            }
+            producer.flush();
+            let _ = consumer.pop();
+            producer.flush();
+            let received = drain(&mut consumer);
+            assert_eq!(received.last(), Some(&(round * 10 + 4)));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Build configuration summary for support and debug dumps
+use core::fmt;
+
+use crate::{packet::SbusPacket, CHANNEL_COUNT, SBUS_FRAME_LENGTH};
+
+/// Every Cargo feature of the crate and whether this build enables it
+const FEATURES: &[(&str, bool)] = &[
+    ("blocking", cfg!(feature = "blocking")),
+    ("async", cfg!(feature = "async")),
+    ("std", cfg!(feature = "std")),
+    ("defmt", cfg!(feature = "defmt")),
+    ("json", cfg!(feature = "json")),
+    ("crsf", cfg!(feature = "crsf")),
+    ("elrs", cfg!(feature = "elrs")),
+    ("blackbox", cfg!(feature = "blackbox")),
+    ("uavcan", cfg!(feature = "uavcan")),
+    ("openpilot", cfg!(feature = "openpilot")),
+    ("sitl", cfg!(feature = "sitl")),
+    ("flightgear", cfg!(feature = "flightgear")),
+    ("frsky", cfg!(feature = "frsky")),
+    ("xplane", cfg!(feature = "xplane")),
+    ("jsbsim", cfg!(feature = "jsbsim")),
+    ("betaflight", cfg!(feature = "betaflight")),
+    ("proptest", cfg!(feature = "proptest")),
+    ("test-util", cfg!(feature = "test-util")),
+    ("opentx", cfg!(feature = "opentx")),
+    ("dronecan", cfg!(feature = "dronecan")),
+    ("fport", cfg!(feature = "fport")),
+    ("srxl", cfg!(feature = "srxl")),
+    ("msp", cfg!(feature = "msp")),
+    ("hott", cfg!(feature = "hott")),
+    ("hexflight", cfg!(feature = "hexflight")),
+    ("qgc", cfg!(feature = "qgc")),
+    ("opensbus", cfg!(feature = "opensbus")),
+    ("wifibroadcast", cfg!(feature = "wifibroadcast")),
+    ("cyphal", cfg!(feature = "cyphal")),
+    ("opencockpits", cfg!(feature = "opencockpits")),
+    ("soft-uart", cfg!(feature = "soft-uart")),
+];
+
+/// Version, enabled features and frame geometry of this build of the crate
+///
+/// Formats as e.g. `sbus-rs 0.1.2 (features: blocking, std; frame 25 bytes, 16
+/// channels x 11 bits)`, suitable for pasting into an issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    /// Every feature of the crate with its state in this build
+    pub features: &'static [(&'static str, bool)],
+    pub frame_length: usize,
+    pub channel_count: usize,
+    pub channel_bits: u8,
+}
+
+impl BuildInfo {
+    /// Returns `true` if the feature `name` is enabled in this build
+    pub fn has_feature(&self, name: &str) -> bool {
+        self.features
+            .iter()
+            .any(|&(feature, enabled)| enabled && feature == name)
+    }
+
+    /// Names of the features enabled in this build
+    pub fn enabled_features(&self) -> impl Iterator<Item = &'static str> {
+        self.features
+            .iter()
+            .filter(|&&(_, enabled)| enabled)
+            .map(|&(name, _)| name)
+    }
+}
+
+/// Returns the configuration this crate was compiled with
+pub const fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        features: FEATURES,
+        frame_length: SBUS_FRAME_LENGTH,
+        channel_count: CHANNEL_COUNT,
+        channel_bits: SbusPacket::channel_resolution_bits(),
+    }
+}
+
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sbus-rs {} (features: ", self.version)?;
+        let mut none = true;
+        for name in self.enabled_features() {
+            if !none {
+                f.write_str(", ")?;
+            }
+            f.write_str(name)?;
+            none = false;
+        }
+        if none {
+            f.write_str("none")?;
+        }
+        write!(
+            f,
+            "; frame {} bytes, {} channels x {} bits)",
+            self.frame_length, self.channel_count, self.channel_bits
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for BuildInfo {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "sbus-rs {=str} (features: ", self.version);
+        let mut none = true;
+        for name in self.enabled_features() {
+            if !none {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "{=str}", name);
+            none = false;
+        }
+        if none {
+            defmt::write!(f, "none");
+        }
+        defmt::write!(
+            f,
+            "; frame {=usize} bytes, {=usize} channels x {=u8} bits)",
+            self.frame_length,
+            self.channel_count,
+            self.channel_bits
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_features_match_manifest() {
+        let manifest = include_str!("../Cargo.toml");
+        let section = manifest
+            .split("[features]")
+            .nth(1)
+            .and_then(|s| s.split("\n\n").next())
+            .unwrap();
+        let declared: Vec<&str> = section
+            .lines()
+            .filter_map(|line| line.split_once(" = ").map(|(name, _)| name))
+            .filter(|&name| name != "default")
+            .collect();
+        let listed: Vec<&str> = build_info().features.iter().map(|&(n, _)| n).collect();
+        assert_eq!(listed, declared);
+    }
+
+    #[test]
+    fn test_feature_states_match_build() {
+        let info = build_info();
+        assert_eq!(info.has_feature("std"), cfg!(feature = "std"));
+        assert_eq!(info.has_feature("blocking"), cfg!(feature = "blocking"));
+        assert_eq!(info.has_feature("defmt"), cfg!(feature = "defmt"));
+        assert!(!info.has_feature("default"));
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn test_display() {
+        let info = BuildInfo {
+            version: "1.2.3",
+            features: &[("std", true), ("json", false), ("crsf", true)],
+            ..build_info()
+        };
+        assert_eq!(
+            info.to_string(),
+            "sbus-rs 1.2.3 (features: std, crsf; frame 25 bytes, 16 channels x 11 bits)"
+        );
+        let info = BuildInfo {
+            features: &[("std", false)],
+            ..info
+        };
+        assert!(info.to_string().contains("(features: none; frame"));
+    }
+}
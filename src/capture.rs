@@ -0,0 +1,292 @@
+//! Scanning and indexing of raw SBUS captures
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::vec::Vec;
+
+use crate::{packet::SbusPacket, SbusError, SBUS_FRAME_LENGTH, SBUS_HEADER};
+
+/// Number of bytes requested from the reader at a time
+const CHUNK_SIZE: usize = 4096;
+
+/// Magic bytes at the start of a saved [`FrameIndex`]
+const INDEX_MAGIC: &[u8; 4] = b"SBIX";
+
+/// Scans a capture for valid SBUS frames, reporting each frame's absolute offset
+///
+/// Offsets are relative to the start of the stream, so scanning may begin from any
+/// position. Bytes that do not start a valid frame are skipped. An I/O error is
+/// reported once as `SbusError::ReadError` and ends the iteration.
+pub fn scan_reader<R: Read + Seek>(
+    reader: R,
+) -> impl Iterator<Item = (u64, Result<SbusPacket, SbusError>)> {
+    let mut scanner = CaptureScanner::new(reader);
+    core::iter::from_fn(move || match scanner.next_frame() {
+        Ok(Some((offset, packet))) => Some((offset, Ok(packet))),
+        Ok(None) => None,
+        Err(_) => {
+            let offset = scanner.position();
+            scanner.finish();
+            Some((offset, Err(SbusError::ReadError)))
+        }
+    })
+}
+
+struct CaptureScanner<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    /// Index of the next unscanned byte in `buffer`
+    cursor: usize,
+    /// Absolute stream offset of `buffer[0]`
+    base: Option<u64>,
+    eof: bool,
+}
+
+impl<R: Read + Seek> CaptureScanner<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::with_capacity(CHUNK_SIZE + SBUS_FRAME_LENGTH),
+            cursor: 0,
+            base: None,
+            eof: false,
+        }
+    }
+
+    fn position(&self) -> u64 {
+        self.base.unwrap_or(0) + self.cursor as u64
+    }
+
+    fn finish(&mut self) {
+        self.eof = true;
+        self.buffer.clear();
+        self.cursor = 0;
+    }
+
+    /// Keeps the unscanned tail and appends the next chunk; returns `false` at EOF
+    fn refill(&mut self) -> io::Result<bool> {
+        if self.eof {
+            return Ok(false);
+        }
+        let base = match self.base {
+            Some(base) => base,
+            None => self.reader.stream_position()?,
+        };
+        self.buffer.drain(..self.cursor);
+        self.base = Some(base + self.cursor as u64);
+        self.cursor = 0;
+
+        let len = self.buffer.len();
+        self.buffer.resize(len + CHUNK_SIZE, 0);
+        let read = loop {
+            match self.reader.read(&mut self.buffer[len..]) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result,
+            }
+        };
+        let read = read.inspect_err(|_| self.buffer.truncate(len))?;
+        self.buffer.truncate(len + read);
+        self.eof = read == 0;
+        Ok(!self.eof)
+    }
+
+    fn next_frame(&mut self) -> io::Result<Option<(u64, SbusPacket)>> {
+        loop {
+            while self.cursor + SBUS_FRAME_LENGTH <= self.buffer.len() {
+                let start = self.cursor;
+                if self.buffer[start] == SBUS_HEADER {
+                    let frame: &[u8; SBUS_FRAME_LENGTH] = self.buffer
+                        [start..start + SBUS_FRAME_LENGTH]
+                        .try_into()
+                        .expect("slice has frame length");
+                    if let Ok(packet) = SbusPacket::from_array(frame) {
+                        self.cursor += SBUS_FRAME_LENGTH;
+                        return Ok(Some((self.base.unwrap_or(0) + start as u64, packet)));
+                    }
+                }
+                self.cursor += 1;
+            }
+            if !self.refill()? {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+/// Offsets of every valid frame in a capture, for random access by frame number
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrameIndex {
+    offsets: Vec<u64>,
+}
+
+impl FrameIndex {
+    /// Scans the whole capture from the reader's current position
+    pub fn build<R: Read + Seek>(reader: R) -> io::Result<Self> {
+        let mut scanner = CaptureScanner::new(reader);
+        let mut offsets = Vec::new();
+        while let Some((offset, _)) = scanner.next_frame()? {
+            offsets.push(offset);
+        }
+        Ok(Self { offsets })
+    }
+
+    /// Number of indexed frames
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Absolute offset of frame `n`
+    pub fn offset(&self, n: usize) -> Option<u64> {
+        self.offsets.get(n).copied()
+    }
+
+    /// Seeks the reader to the start of frame `n`
+    pub fn seek_to_frame<S: Seek>(&self, seeker: &mut S, n: usize) -> io::Result<u64> {
+        let offset = self.offset(n).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "frame number out of range")
+        })?;
+        seeker.seek(SeekFrom::Start(offset))
+    }
+
+    /// Writes the index as `SBIX`, a little-endian `u64` count and the `u64`
+    /// offsets
+    pub fn save<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(INDEX_MAGIC)?;
+        writer.write_all(&(self.offsets.len() as u64).to_le_bytes())?;
+        for offset in &self.offsets {
+            writer.write_all(&offset.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Reads an index written by [`FrameIndex::save`]
+    pub fn load<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != INDEX_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not an SBUS frame index",
+            ));
+        }
+
+        let mut word = [0u8; 8];
+        reader.read_exact(&mut word)?;
+        let count = u64::from_le_bytes(word);
+        let mut offsets = Vec::new();
+        for _ in 0..count {
+            reader.read_exact(&mut word)?;
+            offsets.push(u64::from_le_bytes(word));
+        }
+        Ok(Self { offsets })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+    use std::io::Cursor;
+
+    /// Channel value of frame `i`, chosen so that no data or flag byte is zero
+    fn value(i: usize) -> u16 {
+        0x555 ^ (i as u16 % 64)
+    }
+
+    fn frame(i: usize) -> [u8; SBUS_FRAME_LENGTH] {
+        SbusPacket {
+            channels: [value(i); 16],
+            flags: Flags::from_byte(1 + (i % 15) as u8),
+        }
+        .to_array()
+    }
+
+    /// Frames separated by noise, including stray header bytes, with known offsets
+    ///
+    /// A stray header only decodes as a frame if a zero byte follows 24 bytes later,
+    /// which the frames above never contain before their footer.
+    fn capture(frames: usize) -> (Vec<u8>, Vec<u64>) {
+        let mut data = vec![0xFF, SBUS_HEADER, 0x12];
+        let mut offsets = Vec::new();
+        for i in 0..frames {
+            offsets.push(data.len() as u64);
+            data.extend_from_slice(&frame(i));
+            data.extend(core::iter::repeat_n(SBUS_HEADER, i % 7));
+            data.extend(core::iter::repeat_n(0xA5, i % 3));
+        }
+        data.extend_from_slice(&frame(0)[..10]);
+        (data, offsets)
+    }
+
+    #[test]
+    fn test_scan_offsets() {
+        let (data, offsets) = capture(5);
+        let found: Vec<_> = scan_reader(Cursor::new(data)).collect();
+        assert_eq!(found.len(), offsets.len());
+        for (i, ((offset, packet), expected)) in found.into_iter().zip(offsets).enumerate() {
+            assert_eq!(offset, expected);
+            assert_eq!(packet.unwrap().channels, [value(i); 16]);
+        }
+    }
+
+    #[test]
+    fn test_scan_across_chunks() {
+        let (data, offsets) = capture(1000);
+        assert!(data.len() > 4 * CHUNK_SIZE);
+        let found: Vec<u64> = scan_reader(Cursor::new(data)).map(|(o, _)| o).collect();
+        assert_eq!(found, offsets);
+    }
+
+    #[test]
+    fn test_scan_from_current_position() {
+        let (data, offsets) = capture(3);
+        let mut cursor = Cursor::new(data);
+        cursor.seek(SeekFrom::Start(offsets[1])).unwrap();
+        let found: Vec<u64> = scan_reader(cursor).map(|(o, _)| o).collect();
+        assert_eq!(found, offsets[1..]);
+    }
+
+    #[test]
+    fn test_frame_index_seek() {
+        let (data, offsets) = capture(20);
+        let mut cursor = Cursor::new(data);
+        let index = FrameIndex::build(&mut cursor).unwrap();
+        assert_eq!(index.len(), 20);
+
+        index.seek_to_frame(&mut cursor, 13).unwrap();
+        assert_eq!(cursor.position(), offsets[13]);
+        let (offset, packet) = scan_reader(&mut cursor).next().unwrap();
+        assert_eq!(offset, offsets[13]);
+        assert_eq!(packet.unwrap().channels, [value(13); 16]);
+
+        assert!(index.seek_to_frame(&mut cursor, 20).is_err());
+    }
+
+    #[test]
+    fn test_frame_index_save_load() {
+        let (data, _) = capture(8);
+        let index = FrameIndex::build(Cursor::new(data)).unwrap();
+
+        let mut saved = Vec::new();
+        index.save(&mut saved).unwrap();
+        assert_eq!(saved.len(), 4 + 8 + 8 * 8);
+        assert_eq!(FrameIndex::load(saved.as_slice()).unwrap(), index);
+
+        saved[0] = b'X';
+        assert!(FrameIndex::load(saved.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_scan_header_noise() {
+        // Every byte is a candidate header, but each failed candidate costs one byte
+        let mut data = vec![SBUS_HEADER; 1000];
+        data.extend_from_slice(&frame(1));
+        data.extend(core::iter::repeat_n(SBUS_HEADER, 2 * SBUS_FRAME_LENGTH));
+        data.extend_from_slice(&frame(2));
+        data.extend(core::iter::repeat_n(SBUS_HEADER, CHUNK_SIZE));
+        let found: Vec<u64> = scan_reader(Cursor::new(data)).map(|(o, _)| o).collect();
+        assert_eq!(found, [1000, 1000 + 3 * SBUS_FRAME_LENGTH as u64]);
+    }
+}
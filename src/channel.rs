@@ -0,0 +1,397 @@
+//! Channel identifiers and stick assignments
+use core::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    conversion::{ticks_to_bipolar, ticks_to_unipolar},
+    error::ChannelIdError,
+    packet::{Flags, SbusPacket},
+    CHANNEL_COUNT,
+};
+
+/// Identifier of one of the 16 SBUS channels
+///
+/// Stores a zero-based index that is always below [`CHANNEL_COUNT`], so indexing a
+/// packet with it never panics. Parsed from and displayed as the 1-based names
+/// `ch1`..`ch16` used in configuration files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChannelId(u8);
+
+impl ChannelId {
+    /// Creates a channel identifier from a zero-based index
+    pub const fn new(index: usize) -> Result<Self, ChannelIdError> {
+        if index < CHANNEL_COUNT {
+            Ok(Self(index as u8))
+        } else {
+            Err(ChannelIdError::OutOfRange(index))
+        }
+    }
+
+    /// Returns the zero-based channel index
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl TryFrom<usize> for ChannelId {
+    type Error = ChannelIdError;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        Self::new(index)
+    }
+}
+
+impl From<ChannelId> for usize {
+    fn from(id: ChannelId) -> Self {
+        id.index()
+    }
+}
+
+impl FromStr for ChannelId {
+    type Err = ChannelIdError;
+
+    /// Parses a 1-based channel name such as `ch1` or `CH16`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = s
+            .get(..2)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("ch"))
+            .map(|_| &s[2..])
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|digits| digits.parse::<usize>().ok())
+            .ok_or(ChannelIdError::UnknownName)?;
+        match number.checked_sub(1) {
+            Some(index) => Self::new(index).map_err(|_| ChannelIdError::OutOfRange(number)),
+            None => Err(ChannelIdError::OutOfRange(number)),
+        }
+    }
+}
+
+impl fmt::Display for ChannelId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ch{}", self.index() + 1)
+    }
+}
+
+/// Assignment of the primary stick functions to channels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelAssignment {
+    pub roll: ChannelId,
+    pub pitch: ChannelId,
+    pub throttle: ChannelId,
+    pub yaw: ChannelId,
+}
+
+impl ChannelAssignment {
+    /// Aileron, elevator, throttle, rudder on channels 1-4 (Futaba/Betaflight default)
+    pub const AETR: Self = Self {
+        roll: ChannelId(0),
+        pitch: ChannelId(1),
+        throttle: ChannelId(2),
+        yaw: ChannelId(3),
+    };
+
+    /// Throttle, aileron, elevator, rudder on channels 1-4 (Spektrum/Graupner)
+    pub const TAER: Self = Self {
+        throttle: ChannelId(0),
+        roll: ChannelId(1),
+        pitch: ChannelId(2),
+        yaw: ChannelId(3),
+    };
+
+    /// Resolves a channel name, accepting `ch1`..`ch16` as well as the aliases
+    /// `roll`, `pitch`, `throttle` and `yaw` (case-insensitive)
+    pub fn resolve(&self, name: &str) -> Result<ChannelId, ChannelIdError> {
+        [
+            ("roll", self.roll),
+            ("pitch", self.pitch),
+            ("throttle", self.throttle),
+            ("yaw", self.yaw),
+        ]
+        .into_iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or_else(|| name.parse(), |(_, id)| Ok(id))
+    }
+
+    /// Returns `true` if the four functions are assigned to distinct channels
+    pub fn is_valid(&self) -> bool {
+        let sticks = self.sticks();
+        (0..sticks.len()).all(|i| !sticks[i + 1..].contains(&sticks[i]))
+    }
+
+    fn sticks(&self) -> [ChannelId; 4] {
+        [self.roll, self.pitch, self.throttle, self.yaw]
+    }
+
+    /// Channels not assigned to a stick function, in ascending order
+    fn aux_channels(&self) -> impl Iterator<Item = usize> {
+        let sticks = self.sticks();
+        (0..CHANNEL_COUNT).filter(move |&i| !sticks.iter().any(|id| id.index() == i))
+    }
+}
+
+impl Default for ChannelAssignment {
+    fn default() -> Self {
+        Self::AETR
+    }
+}
+
+/// Named view of a packet's channels under a [`ChannelAssignment`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NamedChannels {
+    pub roll: u16,
+    pub pitch: u16,
+    pub throttle: u16,
+    pub yaw: u16,
+    /// Remaining channels in ascending channel order
+    pub aux: [u16; 12],
+}
+
+impl NamedChannels {
+    /// Rebuilds a packet from the named view
+    ///
+    /// Exact inverse of [`SbusPacket::named`] when `assign` is valid (see
+    /// [`ChannelAssignment::is_valid`]).
+    pub fn to_packet(&self, assign: &ChannelAssignment, flags: Flags) -> SbusPacket {
+        let mut packet = SbusPacket {
+            channels: [0; CHANNEL_COUNT],
+            flags,
+        };
+        assign
+            .aux_channels()
+            .zip(self.aux)
+            .for_each(|(i, value)| packet.channels[i] = value);
+        packet[assign.roll] = self.roll;
+        packet[assign.pitch] = self.pitch;
+        packet[assign.throttle] = self.throttle;
+        packet[assign.yaw] = self.yaw;
+        packet
+    }
+}
+
+/// Normalized command vector for control algorithms
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RcCommand {
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub throttle: f32,
+    /// Remaining channels in ascending channel order
+    pub aux: [f32; 12],
+}
+
+impl SbusPacket {
+    /// Returns a view of the channels named according to `assign`
+    pub fn named(&self, assign: &ChannelAssignment) -> NamedChannels {
+        let mut aux = [0; 12];
+        aux.iter_mut()
+            .zip(assign.aux_channels())
+            .for_each(|(value, i)| *value = self.channels[i]);
+
+        NamedChannels {
+            roll: self[assign.roll],
+            pitch: self[assign.pitch],
+            throttle: self[assign.throttle],
+            yaw: self[assign.yaw],
+            aux,
+        }
+    }
+
+    /// Returns the normalized stick and auxiliary commands under `assign`
+    ///
+    /// Roll, pitch, yaw and the auxiliary channels are mapped to `-1.0..=1.0` and
+    /// throttle to `0.0..=1.0`.
+    pub fn to_rc_command(&self, assign: &ChannelAssignment) -> RcCommand {
+        let named = self.named(assign);
+        RcCommand {
+            roll: ticks_to_bipolar(named.roll),
+            pitch: ticks_to_bipolar(named.pitch),
+            yaw: ticks_to_bipolar(named.yaw),
+            throttle: ticks_to_unipolar(named.throttle),
+            aux: named.aux.map(ticks_to_bipolar),
+        }
+    }
+}
+
+impl Index<ChannelId> for SbusPacket {
+    type Output = u16;
+
+    fn index(&self, id: ChannelId) -> &Self::Output {
+        &self.channels[id.index()]
+    }
+}
+
+impl IndexMut<ChannelId> for SbusPacket {
+    fn index_mut(&mut self, id: ChannelId) -> &mut Self::Output {
+        &mut self.channels[id.index()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_parse_channel_names() {
+        assert_eq!("ch1".parse(), Ok(ChannelId(0)));
+        assert_eq!("CH16".parse(), Ok(ChannelId(15)));
+        assert_eq!("Ch7".parse::<ChannelId>().map(ChannelId::index), Ok(6));
+    }
+
+    #[test]
+    fn test_parse_channel_failures() {
+        assert_eq!(
+            "ch0".parse::<ChannelId>(),
+            Err(ChannelIdError::OutOfRange(0))
+        );
+        assert_eq!(
+            "ch17".parse::<ChannelId>(),
+            Err(ChannelIdError::OutOfRange(17))
+        );
+        for name in [
+            "", "c", "ch", "ch-1", "ch+1", "channel1", "1", "ch1 ", "roll",
+        ] {
+            assert_eq!(
+                name.parse::<ChannelId>(),
+                Err(ChannelIdError::UnknownName),
+                "{:?} should not parse",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for index in 0..CHANNEL_COUNT {
+            let id = ChannelId::new(index).unwrap();
+            assert_eq!(id.to_string().parse(), Ok(id));
+        }
+        assert_eq!(ChannelId::new(9).unwrap().to_string(), "ch10");
+    }
+
+    #[test]
+    fn test_index_conversions() {
+        assert_eq!(ChannelId::try_from(15).map(usize::from), Ok(15));
+        assert_eq!(ChannelId::try_from(16), Err(ChannelIdError::OutOfRange(16)));
+    }
+
+    #[test]
+    fn test_alias_resolution() {
+        let aetr = ChannelAssignment::AETR;
+        let taer = ChannelAssignment::TAER;
+        assert_eq!(aetr.resolve("throttle"), Ok(ChannelId(2)));
+        assert_eq!(taer.resolve("Throttle"), Ok(ChannelId(0)));
+        assert_eq!(taer.resolve("YAW"), Ok(ChannelId(3)));
+        assert_eq!(taer.resolve("ch5"), Ok(ChannelId(4)));
+        assert_eq!(taer.resolve("collective"), Err(ChannelIdError::UnknownName));
+    }
+
+    #[test]
+    fn test_packet_indexing() {
+        let mut packet = SbusPacket::default();
+        let throttle = ChannelAssignment::TAER.throttle;
+        packet[throttle] = 1811;
+        assert_eq!(packet.channels[0], 1811);
+        assert_eq!(packet[throttle], 1811);
+    }
+
+    fn ascending_packet() -> SbusPacket {
+        let mut packet = SbusPacket::default();
+        packet
+            .channels
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = 100 + i as u16);
+        packet
+    }
+
+    #[test]
+    fn test_assignment_validity() {
+        assert!(ChannelAssignment::AETR.is_valid());
+        assert!(ChannelAssignment::TAER.is_valid());
+        let duplicate = ChannelAssignment {
+            yaw: ChannelId(0),
+            ..ChannelAssignment::AETR
+        };
+        assert!(!duplicate.is_valid());
+    }
+
+    #[test]
+    fn test_named_aetr() {
+        let named = ascending_packet().named(&ChannelAssignment::AETR);
+        assert_eq!(named.roll, 100);
+        assert_eq!(named.pitch, 101);
+        assert_eq!(named.throttle, 102);
+        assert_eq!(named.yaw, 103);
+        assert_eq!(named.aux[0], 104);
+        assert_eq!(named.aux[11], 115);
+    }
+
+    #[test]
+    fn test_named_taer() {
+        let named = ascending_packet().named(&ChannelAssignment::TAER);
+        assert_eq!(named.throttle, 100);
+        assert_eq!(named.roll, 101);
+        assert_eq!(named.pitch, 102);
+        assert_eq!(named.yaw, 103);
+        assert_eq!(named.aux[0], 104);
+    }
+
+    #[test]
+    fn test_named_round_trip() {
+        let packet = SbusPacket {
+            flags: Flags::from_byte(0b1010),
+            ..ascending_packet()
+        };
+        let scattered = ChannelAssignment {
+            roll: ChannelId(15),
+            pitch: ChannelId(7),
+            throttle: ChannelId(0),
+            yaw: ChannelId(8),
+        };
+        for assign in [ChannelAssignment::AETR, ChannelAssignment::TAER, scattered] {
+            let named = packet.named(&assign);
+            assert_eq!(named.to_packet(&assign, packet.flags), packet);
+        }
+        assert_eq!(packet.named(&scattered).aux[6], 109);
+    }
+
+    #[test]
+    fn test_rc_command_extremes() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        let centered = packet.to_rc_command(&ChannelAssignment::AETR);
+        assert_eq!(
+            (centered.roll, centered.pitch, centered.yaw),
+            (0.0, 0.0, 0.0)
+        );
+        assert_eq!(centered.aux, [0.0; 12]);
+
+        packet.channels[0] = 0;
+        packet.channels[1] = CHANNEL_MAX;
+        packet.channels[2] = 0;
+        packet.channels[3] = CHANNEL_MAX;
+        packet.channels[15] = 0;
+        let command = packet.to_rc_command(&ChannelAssignment::AETR);
+        assert_eq!(command.roll, -1.0);
+        assert_eq!(command.pitch, 1.0);
+        assert_eq!(command.throttle, 0.0);
+        assert_eq!(command.yaw, 1.0);
+        assert_eq!(command.aux[11], -1.0);
+
+        // Under TAER channel 0 is the throttle, at its lowest
+        let command = packet.to_rc_command(&ChannelAssignment::TAER);
+        assert_eq!(command.throttle, 0.0);
+        assert_eq!(command.roll, 1.0);
+        assert_eq!(command.pitch, -1.0);
+
+        packet.channels[2] = CHANNEL_MAX;
+        let command = packet.to_rc_command(&ChannelAssignment::AETR);
+        assert_eq!(command.throttle, 1.0);
+    }
+}
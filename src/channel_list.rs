@@ -0,0 +1,169 @@
+//! Parsing of human-entered channel lists
+use crate::{
+    conversion::{us_to_ticks, US_MAX, US_MIN},
+    error::{ParseChannelsError, ParseChannelsErrorKind},
+    packet::SbusPacket,
+    CHANNEL_COUNT, CHANNEL_MAX, CHANNEL_MID,
+};
+
+/// Unit of channel values in a human-entered channel list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelUnit {
+    /// Raw channel values, `0..=CHANNEL_MAX`
+    Ticks,
+    /// Pulse widths in microseconds, `US_MIN..=US_MAX`
+    Microseconds,
+}
+
+impl ChannelUnit {
+    fn to_ticks(self, value: u16) -> Option<u16> {
+        match self {
+            ChannelUnit::Ticks => (value <= CHANNEL_MAX).then_some(value),
+            ChannelUnit::Microseconds => (US_MIN..=US_MAX)
+                .contains(&value)
+                .then(|| us_to_ticks(value)),
+        }
+    }
+}
+
+impl SbusPacket {
+    /// Parses a channel list such as `1500,1500,1000,1500` or `992x16`
+    ///
+    /// The list is comma-separated; each entry is either a single value or a
+    /// `VALUExCOUNT` repetition. Channels not covered by the list are set to
+    /// [`CHANNEL_MID`] and all flags are cleared. Errors report the byte offset of
+    /// the offending entry.
+    pub fn from_str_channels(s: &str, unit: ChannelUnit) -> Result<SbusPacket, ParseChannelsError> {
+        let mut channels = [CHANNEL_MID; CHANNEL_COUNT];
+        let mut next: usize = 0;
+        let mut offset = 0;
+
+        for raw_token in s.split(',') {
+            let token = raw_token.trim();
+            let position = offset + (raw_token.len() - raw_token.trim_start().len());
+            offset += raw_token.len() + 1;
+            let error = |kind| ParseChannelsError { position, kind };
+
+            if token.is_empty() {
+                return Err(error(ParseChannelsErrorKind::EmptyToken));
+            }
+
+            let (value, count) = match token.split_once(['x', 'X']) {
+                Some((value, count)) => {
+                    let count = count
+                        .trim()
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&count| count > 0)
+                        .ok_or(error(ParseChannelsErrorKind::InvalidCount))?;
+                    (value.trim(), count)
+                }
+                None => (token, 1),
+            };
+
+            let value = value
+                .parse::<u16>()
+                .map_err(|_| error(ParseChannelsErrorKind::InvalidValue))?;
+            let ticks = unit
+                .to_ticks(value)
+                .ok_or(error(ParseChannelsErrorKind::OutOfRange(value)))?;
+
+            let end = next
+                .checked_add(count)
+                .filter(|&end| end <= CHANNEL_COUNT)
+                .ok_or(error(ParseChannelsErrorKind::TooManyChannels))?;
+            channels[next..end].fill(ticks);
+            next = end;
+        }
+
+        Ok(SbusPacket {
+            channels,
+            ..SbusPacket::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str, unit: ChannelUnit) -> Result<[u16; CHANNEL_COUNT], ParseChannelsError> {
+        SbusPacket::from_str_channels(s, unit).map(|packet| packet.channels)
+    }
+
+    fn error(position: usize, kind: ParseChannelsErrorKind) -> ParseChannelsError {
+        ParseChannelsError { position, kind }
+    }
+
+    #[test]
+    fn test_comma_list_in_microseconds() {
+        let channels = parse("1500,1500,1000,2000", ChannelUnit::Microseconds).unwrap();
+        assert_eq!(channels[..4], [CHANNEL_MID, CHANNEL_MID, 0, CHANNEL_MAX]);
+        assert!(channels[4..].iter().all(|&value| value == CHANNEL_MID));
+    }
+
+    #[test]
+    fn test_repetition_shorthand() {
+        assert_eq!(
+            parse("992x16", ChannelUnit::Ticks),
+            Ok([992; CHANNEL_COUNT])
+        );
+
+        let channels = parse(" 172 x 4 , 1811 ", ChannelUnit::Ticks).unwrap();
+        assert_eq!(channels[..5], [172, 172, 172, 172, 1811]);
+        assert_eq!(channels[5], CHANNEL_MID);
+    }
+
+    #[test]
+    fn test_value_errors() {
+        assert_eq!(
+            parse("1500,abc", ChannelUnit::Microseconds),
+            Err(error(5, ParseChannelsErrorKind::InvalidValue))
+        );
+        assert_eq!(
+            parse("1500, 2100", ChannelUnit::Microseconds),
+            Err(error(6, ParseChannelsErrorKind::OutOfRange(2100)))
+        );
+        assert_eq!(
+            parse("2048", ChannelUnit::Ticks),
+            Err(error(0, ParseChannelsErrorKind::OutOfRange(2048)))
+        );
+        assert_eq!(
+            parse("992,,992", ChannelUnit::Ticks),
+            Err(error(4, ParseChannelsErrorKind::EmptyToken))
+        );
+        assert_eq!(
+            parse("", ChannelUnit::Ticks),
+            Err(error(0, ParseChannelsErrorKind::EmptyToken))
+        );
+    }
+
+    #[test]
+    fn test_count_errors() {
+        assert_eq!(
+            parse("992x0", ChannelUnit::Ticks),
+            Err(error(0, ParseChannelsErrorKind::InvalidCount))
+        );
+        assert_eq!(
+            parse("992,992xy", ChannelUnit::Ticks),
+            Err(error(4, ParseChannelsErrorKind::InvalidCount))
+        );
+        assert_eq!(
+            parse("992x15,172,172", ChannelUnit::Ticks),
+            Err(error(11, ParseChannelsErrorKind::TooManyChannels))
+        );
+        assert_eq!(
+            parse("1024,1024x18446744073709551615", ChannelUnit::Ticks),
+            Err(error(5, ParseChannelsErrorKind::TooManyChannels))
+        );
+    }
+
+    #[test]
+    fn test_error_message() {
+        let err = parse("1500,1500,3000", ChannelUnit::Microseconds).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "channel value 3000 out of range at position 10"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Compact, log-friendly packet formatting
+use core::fmt;
+
+use crate::{packet::SbusPacket, CHANNEL_MID};
+
+/// Compact view of an [`SbusPacket`] that only shows channels away from neutral
+///
+/// Formats as e.g. `ch3=172 ch5=1811 FS`, with 1-based channel numbers, followed by
+/// the active flags (`D1`, `D2`, `FL` for frame lost, `FS` for failsafe). When no
+/// channel deviates from [`CHANNEL_MID`] by more than the threshold, the channel
+/// list is replaced by `neutral`.
+#[derive(Debug, Clone, Copy)]
+pub struct Compact<'a> {
+    packet: &'a SbusPacket,
+    threshold: u16,
+}
+
+impl Compact<'_> {
+    /// Default deviation threshold, wide enough to treat the conventional
+    /// receiver center (992) as neutral
+    pub const DEFAULT_THRESHOLD: u16 = 48;
+
+    fn deviating(&self) -> impl Iterator<Item = (usize, u16)> + '_ {
+        self.packet
+            .channels
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, value)| value.abs_diff(CHANNEL_MID) > self.threshold)
+    }
+
+    fn flag_labels(&self) -> impl Iterator<Item = &'static str> {
+        let flags = self.packet.flags;
+        [
+            (flags.d1, "D1"),
+            (flags.d2, "D2"),
+            (flags.frame_lost, "FL"),
+            (flags.failsafe, "FS"),
+        ]
+        .into_iter()
+        .filter_map(|(set, label)| set.then_some(label))
+    }
+}
+
+impl SbusPacket {
+    /// Returns a compact formatter using [`Compact::DEFAULT_THRESHOLD`]
+    pub fn compact(&self) -> Compact<'_> {
+        self.compact_with_threshold(Compact::DEFAULT_THRESHOLD)
+    }
+
+    /// Returns a compact formatter that hides channels within `threshold` of
+    /// [`CHANNEL_MID`]
+    pub fn compact_with_threshold(&self, threshold: u16) -> Compact<'_> {
+        Compact {
+            packet: self,
+            threshold,
+        }
+    }
+}
+
+impl fmt::Display for Compact<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut neutral = true;
+        for (i, value) in self.deviating() {
+            if !neutral {
+                f.write_str(" ")?;
+            }
+            write!(f, "ch{}={}", i + 1, value)?;
+            neutral = false;
+        }
+        if neutral {
+            f.write_str("neutral")?;
+        }
+        for label in self.flag_labels() {
+            write!(f, " {}", label)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Compact<'_> {
+    fn format(&self, f: defmt::Formatter) {
+        let mut neutral = true;
+        for (i, value) in self.deviating() {
+            if !neutral {
+                defmt::write!(f, " ");
+            }
+            defmt::write!(f, "ch{=usize}={=u16}", i + 1, value);
+            neutral = false;
+        }
+        if neutral {
+            defmt::write!(f, "neutral");
+        }
+        for label in self.flag_labels() {
+            defmt::write!(f, " {=str}", label);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+
+    fn packet(channels: [u16; 16], flag_byte: u8) -> SbusPacket {
+        SbusPacket {
+            channels,
+            flags: Flags::from_byte(flag_byte),
+        }
+    }
+
+    #[test]
+    fn test_neutral_packet() {
+        let packet = packet([992; 16], 0);
+        assert_eq!(format!("{}", packet.compact()), "neutral");
+    }
+
+    #[test]
+    fn test_single_deviation() {
+        let mut channels = [CHANNEL_MID; 16];
+        channels[2] = 172;
+        let packet = packet(channels, 0);
+        assert_eq!(format!("{}", packet.compact()), "ch3=172");
+    }
+
+    #[test]
+    fn test_failsafe_packet() {
+        let mut channels = [CHANNEL_MID; 16];
+        channels[2] = 172;
+        channels[4] = 1811;
+        assert_eq!(
+            format!("{}", packet(channels, 0b1000).compact()),
+            "ch3=172 ch5=1811 FS"
+        );
+        assert_eq!(
+            format!("{}", packet([CHANNEL_MID; 16], 0b1100).compact()),
+            "neutral FL FS"
+        );
+    }
+
+    #[test]
+    fn test_custom_threshold() {
+        let packet = packet([992; 16], 0);
+        assert_eq!(
+            format!("{}", packet.compact_with_threshold(31)),
+            "ch1=992 ch2=992 ch3=992 ch4=992 ch5=992 ch6=992 ch7=992 ch8=992 \
+             ch9=992 ch10=992 ch11=992 ch12=992 ch13=992 ch14=992 ch15=992 ch16=992"
+        );
+        assert_eq!(format!("{}", packet.compact_with_threshold(32)), "neutral");
+    }
+}
@@ -0,0 +1,119 @@
+//! Dropped-frame detection from channel continuity
+use core::time::Duration;
+
+use crate::packet::SbusPacket;
+
+/// Estimates frames dropped between consecutive packets from channel jumps
+///
+/// SBUS frames carry no sequence number, so a dropped frame only shows as a
+/// channel moving further than it can between two frames. With a per-frame slew
+/// limit of `max_slew`, a jump of `d` counts implies at least `ceil(d / max_slew)`
+/// frame periods, all but one of them missed. The estimate is a lower bound: frames
+/// dropped while the channels hold still are invisible.
+#[derive(Debug, Clone)]
+pub struct ContinuityChecker {
+    frame_period: Duration,
+    max_slew: u16,
+    last: Option<SbusPacket>,
+    missed: u64,
+}
+
+impl ContinuityChecker {
+    /// Creates a checker for frames sent every `frame_period` whose channels move
+    /// by at most `max_slew` counts per frame
+    pub const fn new(frame_period: Duration, max_slew: u16) -> Self {
+        Self {
+            frame_period,
+            max_slew: if max_slew == 0 { 1 } else { max_slew },
+            last: None,
+            missed: 0,
+        }
+    }
+
+    /// Feeds the next packet and returns the number of frames estimated missing
+    /// since the previous one
+    pub fn check(&mut self, packet: &SbusPacket) -> u32 {
+        let missed = match self.last {
+            Some(last) => last
+                .channels
+                .iter()
+                .zip(&packet.channels)
+                .map(|(&old, &new)| old.abs_diff(new).div_ceil(self.max_slew).saturating_sub(1))
+                .max()
+                .unwrap_or(0) as u32,
+            None => 0,
+        };
+        self.last = Some(*packet);
+        self.missed += missed as u64;
+        missed
+    }
+
+    /// Cumulative number of frames estimated missing
+    pub fn missed_frames(&self) -> u64 {
+        self.missed
+    }
+
+    /// Cumulative time without frames implied by [`ContinuityChecker::missed_frames`]
+    pub fn missed_time(&self) -> Duration {
+        self.frame_period
+            .saturating_mul(self.missed.min(u32::MAX as u64) as u32)
+    }
+
+    /// Forgets the previous packet, e.g. after a deliberate pause in the stream
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERIOD: Duration = Duration::from_millis(14);
+
+    fn packet(value: u16) -> SbusPacket {
+        let mut packet = SbusPacket::default();
+        packet.channels[2] = value;
+        packet
+    }
+
+    #[test]
+    fn test_continuous_stream() {
+        let mut checker = ContinuityChecker::new(PERIOD, 16);
+        for value in (0..2000).step_by(16) {
+            assert_eq!(checker.check(&packet(value)), 0);
+        }
+        assert_eq!(checker.missed_frames(), 0);
+    }
+
+    #[test]
+    fn test_dropped_frames_estimate() {
+        // A ramp at 10 counts per frame, with frames dropped at known positions
+        let dropped = [5, 6, 7, 20, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50];
+        let mut checker = ContinuityChecker::new(PERIOD, 12);
+        let mut estimates = heapless::Vec::<u32, 8>::new();
+        for frame in (0..100u16).filter(|frame| !dropped.contains(frame)) {
+            let missed = checker.check(&packet(frame * 10));
+            if missed > 0 {
+                estimates.push(missed).unwrap();
+            }
+        }
+        // 3, 1 and 10 frames missing; the slew margin may hide one per gap
+        assert_eq!(estimates.len(), 3);
+        for (estimate, actual) in estimates.iter().zip([3u32, 1, 10]) {
+            assert!(estimate.abs_diff(actual) <= 1, "{} vs {}", estimate, actual);
+        }
+        let total = checker.missed_frames();
+        assert!(total.abs_diff(dropped.len() as u64) <= 3);
+        assert_eq!(checker.missed_time(), PERIOD * total as u32);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut checker = ContinuityChecker::new(PERIOD, 10);
+        checker.check(&packet(0));
+        checker.reset();
+        assert_eq!(checker.check(&packet(1000)), 0);
+        assert_eq!(checker.check(&packet(1100)), 9);
+    }
+}
@@ -0,0 +1,146 @@
+//! Conversions between raw channel values, servo pulse widths and normalized values
+use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+/// Pulse width, in microseconds, of a channel at 0
+pub const US_MIN: u16 = 1000;
+/// Pulse width, in microseconds, of a channel at [`CHANNEL_MAX`]
+pub const US_MAX: u16 = 2000;
+
+const US_SPAN: u32 = (US_MAX - US_MIN) as u32;
+
+/// Converts a raw channel value to a pulse width in microseconds
+///
+/// Maps `0..=CHANNEL_MAX` linearly onto `US_MIN..=US_MAX`, rounding to the nearest
+/// microsecond. Values above [`CHANNEL_MAX`] are clamped.
+pub const fn ticks_to_us(ticks: u16) -> u16 {
+    let ticks = if ticks > CHANNEL_MAX {
+        CHANNEL_MAX
+    } else {
+        ticks
+    } as u32;
+    let max = CHANNEL_MAX as u32;
+    (US_MIN as u32 + (ticks * US_SPAN + max / 2) / max) as u16
+}
+
+/// Converts a pulse width in microseconds to a raw channel value
+///
+/// Inverse of [`ticks_to_us`]; pulse widths outside `US_MIN..=US_MAX` are clamped.
+pub const fn us_to_ticks(us: u16) -> u16 {
+    let us = if us < US_MIN {
+        US_MIN
+    } else if us > US_MAX {
+        US_MAX
+    } else {
+        us
+    };
+    (((us - US_MIN) as u32 * CHANNEL_MAX as u32 + US_SPAN / 2) / US_SPAN) as u16
+}
+
+/// Converts a raw channel value to the bipolar range `-1.0..=1.0`
+///
+/// [`CHANNEL_MID`] maps to exactly `0.0`, 0 to `-1.0` and [`CHANNEL_MAX`] to `1.0`.
+/// The two halves are scaled separately since the midpoint is not centered in the
+/// 11-bit range. Values above [`CHANNEL_MAX`] are clamped.
+pub fn ticks_to_bipolar(ticks: u16) -> f32 {
+    let ticks = ticks.min(CHANNEL_MAX);
+    if ticks < CHANNEL_MID {
+        (ticks as f32 - CHANNEL_MID as f32) / CHANNEL_MID as f32
+    } else {
+        (ticks - CHANNEL_MID) as f32 / (CHANNEL_MAX - CHANNEL_MID) as f32
+    }
+}
+
+/// Converts a raw channel value to the unipolar range `0.0..=1.0`
+///
+/// Values above [`CHANNEL_MAX`] are clamped.
+pub fn ticks_to_unipolar(ticks: u16) -> f32 {
+    ticks.min(CHANNEL_MAX) as f32 / CHANNEL_MAX as f32
+}
+
+/// Converts a raw channel value to an integer in `-negative..=positive`
+///
+/// [`CHANNEL_MID`] maps to exactly 0, 0 to `-negative` and [`CHANNEL_MAX`] to
+/// `positive`; like [`ticks_to_bipolar`], the two halves are scaled separately.
+/// Results are rounded to the nearest integer and values above [`CHANNEL_MAX`] are
+/// clamped.
+pub const fn ticks_to_signed(ticks: u16, negative: i32, positive: i32) -> i32 {
+    let ticks = if ticks > CHANNEL_MAX {
+        CHANNEL_MAX
+    } else {
+        ticks
+    };
+    let (offset, full, half_span) = if ticks < CHANNEL_MID {
+        (
+            ticks as i32 - CHANNEL_MID as i32,
+            negative,
+            CHANNEL_MID as i32,
+        )
+    } else {
+        (
+            (ticks - CHANNEL_MID) as i32,
+            positive,
+            (CHANNEL_MAX - CHANNEL_MID) as i32,
+        )
+    };
+    let scaled = offset as i64 * full as i64;
+    // Round half away from zero
+    ((scaled + scaled.signum() * half_span as i64 / 2) / half_span as i64) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anchor_points() {
+        assert_eq!(ticks_to_us(0), US_MIN);
+        assert_eq!(ticks_to_us(CHANNEL_MID), 1500);
+        assert_eq!(ticks_to_us(CHANNEL_MAX), US_MAX);
+        assert_eq!(us_to_ticks(US_MIN), 0);
+        assert_eq!(us_to_ticks(1500), CHANNEL_MID);
+        assert_eq!(us_to_ticks(US_MAX), CHANNEL_MAX);
+    }
+
+    #[test]
+    fn test_clamping() {
+        assert_eq!(ticks_to_us(u16::MAX), US_MAX);
+        assert_eq!(us_to_ticks(0), 0);
+        assert_eq!(us_to_ticks(2500), CHANNEL_MAX);
+    }
+
+    #[test]
+    fn test_us_round_trip() {
+        for us in US_MIN..=US_MAX {
+            assert_eq!(ticks_to_us(us_to_ticks(us)), us);
+        }
+    }
+
+    #[test]
+    fn test_normalized_anchor_points() {
+        assert_eq!(ticks_to_bipolar(0), -1.0);
+        assert_eq!(ticks_to_bipolar(CHANNEL_MID), 0.0);
+        assert_eq!(ticks_to_bipolar(CHANNEL_MAX), 1.0);
+        assert_eq!(ticks_to_bipolar(u16::MAX), 1.0);
+        assert_eq!(ticks_to_unipolar(0), 0.0);
+        assert_eq!(ticks_to_unipolar(CHANNEL_MAX), 1.0);
+        assert_eq!(ticks_to_unipolar(u16::MAX), 1.0);
+    }
+
+    #[test]
+    fn test_normalized_monotonic() {
+        for ticks in 1..=CHANNEL_MAX {
+            assert!(ticks_to_bipolar(ticks) > ticks_to_bipolar(ticks - 1));
+            assert!(ticks_to_unipolar(ticks) > ticks_to_unipolar(ticks - 1));
+        }
+    }
+
+    #[test]
+    fn test_signed_anchor_points() {
+        assert_eq!(ticks_to_signed(0, 8192, 8191), -8192);
+        assert_eq!(ticks_to_signed(CHANNEL_MID, 8192, 8191), 0);
+        assert_eq!(ticks_to_signed(CHANNEL_MAX, 8192, 8191), 8191);
+        assert_eq!(ticks_to_signed(u16::MAX, 100, 100), 100);
+        assert_eq!(ticks_to_signed(CHANNEL_MID - 512, 1024, 1024), -512);
+        assert_eq!(ticks_to_signed(CHANNEL_MID + 512, 1024, 1023), 512);
+    }
+}
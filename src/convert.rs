@@ -0,0 +1,110 @@
+//! Conversions between SBUS packets and other RC, telemetry and simulator formats
+mod analog;
+mod betaflight;
+#[cfg(feature = "blackbox")]
+mod blackbox;
+#[cfg(feature = "crsf")]
+mod crsf;
+#[cfg(feature = "cyphal")]
+mod cyphal;
+#[cfg(feature = "dronecan")]
+mod dronecan;
+#[cfg(feature = "json")]
+mod dronekit;
+#[cfg(feature = "elrs")]
+mod elrs;
+#[cfg(feature = "flightgear")]
+mod flightgear;
+#[cfg(feature = "fport")]
+mod fport;
+#[cfg(feature = "frsky")]
+mod frsky;
+mod gyroflow;
+mod haptic;
+#[cfg(feature = "hexflight")]
+mod hexflight;
+#[cfg(feature = "hott")]
+mod hott;
+#[cfg(feature = "std")]
+mod influxdb;
+#[cfg(feature = "jsbsim")]
+mod jsbsim;
+#[cfg(feature = "msp")]
+mod msp;
+mod multilink;
+mod ned;
+#[cfg(feature = "opencockpits")]
+mod opencockpits;
+mod openlrs;
+#[cfg(feature = "openpilot")]
+mod openpilot;
+#[cfg(feature = "opentx")]
+mod opentx;
+#[cfg(feature = "qgc")]
+mod qgc;
+#[cfg(all(feature = "std", feature = "soft-uart"))]
+mod sigrok;
+#[cfg(feature = "sitl")]
+mod sitl;
+#[cfg(feature = "srxl")]
+mod srxl;
+#[cfg(feature = "uavcan")]
+mod uavcan;
+#[cfg(feature = "betaflight")]
+mod vtx;
+#[cfg(feature = "wifibroadcast")]
+mod wifibroadcast;
+#[cfg(feature = "xplane")]
+mod xplane;
+
+pub use betaflight::*;
+#[cfg(feature = "blackbox")]
+pub use blackbox::*;
+#[cfg(feature = "crsf")]
+pub use crsf::*;
+#[cfg(feature = "cyphal")]
+pub use cyphal::*;
+#[cfg(feature = "dronecan")]
+pub use dronecan::*;
+#[cfg(feature = "json")]
+pub use dronekit::*;
+#[cfg(feature = "elrs")]
+pub use elrs::*;
+#[cfg(feature = "flightgear")]
+pub use flightgear::*;
+#[cfg(feature = "fport")]
+pub use fport::*;
+#[cfg(feature = "frsky")]
+pub use frsky::*;
+pub use gyroflow::*;
+pub use haptic::*;
+#[cfg(feature = "hexflight")]
+pub use hexflight::*;
+#[cfg(feature = "hott")]
+pub use hott::*;
+#[cfg(feature = "jsbsim")]
+pub use jsbsim::*;
+#[cfg(feature = "msp")]
+pub use msp::*;
+pub use ned::*;
+#[cfg(feature = "opencockpits")]
+pub use opencockpits::*;
+pub use openlrs::*;
+#[cfg(feature = "openpilot")]
+pub use openpilot::*;
+#[cfg(feature = "opentx")]
+pub use opentx::*;
+#[cfg(feature = "qgc")]
+pub use qgc::*;
+#[cfg(all(feature = "std", feature = "soft-uart"))]
+pub use sigrok::*;
+#[cfg(feature = "sitl")]
+pub use sitl::*;
+#[cfg(feature = "srxl")]
+pub use srxl::*;
+#[cfg(feature = "betaflight")]
+pub use vtx::*;
+#[cfg(feature = "wifibroadcast")]
+pub use wifibroadcast::*;
+#[cfg(feature = "xplane")]
+pub use xplane::*;
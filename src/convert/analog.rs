@@ -0,0 +1,93 @@
+use crate::{packet::SbusPacket, SbusError, CHANNEL_MAX};
+
+impl SbusPacket {
+    /// Converts the channels to DAC output voltages
+    ///
+    /// Maps `0..=CHANNEL_MAX` linearly onto `0.0..=vref` volts. Values above
+    /// [`CHANNEL_MAX`] are clamped.
+    pub fn to_analog_voltages(&self, vref: f32) -> [f32; 16] {
+        self.channels
+            .map(|value| value.min(CHANNEL_MAX) as f32 * vref / CHANNEL_MAX as f32)
+    }
+
+    /// Converts ADC input voltages to channel values
+    ///
+    /// Inverse of [`SbusPacket::to_analog_voltages`], rounding to the nearest
+    /// value. Voltages below 0 V or above `vref` are clamped. Flags are cleared.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbusError::InvalidFormat` if `vref` is not a positive finite number
+    /// or a voltage is NaN.
+    pub fn from_analog_voltages(voltages: &[f32; 16], vref: f32) -> Result<SbusPacket, SbusError> {
+        if !vref.is_finite() || vref <= 0.0 || voltages.iter().any(|v| v.is_nan()) {
+            return Err(SbusError::InvalidFormat);
+        }
+        Ok(SbusPacket {
+            channels: voltages.map(|v| {
+                let scaled = v.clamp(0.0, vref) / vref * CHANNEL_MAX as f32;
+                (scaled + 0.5) as u16
+            }),
+            ..SbusPacket::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHANNEL_MID;
+
+    #[test]
+    fn test_analog_anchor_points() {
+        let mut packet = SbusPacket::default();
+        packet.channels[1] = CHANNEL_MAX;
+        packet.channels[2] = CHANNEL_MID;
+        let voltages = packet.to_analog_voltages(3.3);
+        assert_eq!(voltages[0], 0.0);
+        assert_eq!(voltages[1], 3.3);
+        assert!((voltages[2] - 1.65).abs() < 0.001);
+
+        let decoded = SbusPacket::from_analog_voltages(&voltages, 3.3).unwrap();
+        assert_eq!(decoded, packet);
+        let half = SbusPacket::from_analog_voltages(&[1.65; 16], 3.3).unwrap();
+        assert!(half.channels.iter().all(|&v| v.abs_diff(CHANNEL_MID) <= 1));
+    }
+
+    #[test]
+    fn test_analog_round_trip() {
+        let packet = SbusPacket {
+            channels: core::array::from_fn(|i| i as u16 * 136),
+            ..SbusPacket::default()
+        };
+        for vref in [1.0, 3.3, 5.0] {
+            let voltages = packet.to_analog_voltages(vref);
+            assert_eq!(
+                SbusPacket::from_analog_voltages(&voltages, vref),
+                Ok(packet)
+            );
+        }
+    }
+
+    #[test]
+    fn test_analog_clamping() {
+        let mut voltages = [0.0; 16];
+        voltages[0] = 12.0;
+        voltages[1] = -1.0;
+        voltages[2] = f32::INFINITY;
+        let packet = SbusPacket::from_analog_voltages(&voltages, 5.0).unwrap();
+        assert_eq!(packet.channels[..3], [CHANNEL_MAX, 0, CHANNEL_MAX]);
+
+        voltages[3] = f32::NAN;
+        assert_eq!(
+            SbusPacket::from_analog_voltages(&voltages, 5.0),
+            Err(SbusError::InvalidFormat)
+        );
+        for vref in [0.0, -3.3, f32::NAN, f32::INFINITY] {
+            assert_eq!(
+                SbusPacket::from_analog_voltages(&[1.0; 16], vref),
+                Err(SbusError::InvalidFormat)
+            );
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{packet::SbusPacket, SBUS_FRAME_LENGTH};
+
+/// Channel value Betaflight's SBUS driver treats as stick center.
+///
+/// Betaflight converts SBUS values to pulse widths as `5 * value / 8 + 880` µs, so
+/// 992 is the 1500 µs center of PWM receivers, not the raw 11-bit midpoint.
+pub const BETAFLIGHT_SBUS_MIDPOINT: u16 = 992;
+/// Lowest channel value Betaflight's SBUS driver expects from a receiver.
+pub const BETAFLIGHT_SBUS_MIN: u16 = 173;
+/// Highest channel value Betaflight's SBUS driver expects from a receiver.
+pub const BETAFLIGHT_SBUS_MAX: u16 = 1811;
+
+impl SbusPacket {
+    /// Encodes the packet as the raw 25-byte frame consumed by Betaflight's SBUS
+    /// serial RX driver
+    ///
+    /// Betaflight reads the standard SBUS byte layout, so this is the same frame as
+    /// [`SbusPacket::to_array`]; only the value conventions differ (see
+    /// [`BETAFLIGHT_SBUS_MIN`], [`BETAFLIGHT_SBUS_MIDPOINT`] and
+    /// [`BETAFLIGHT_SBUS_MAX`]).
+    pub fn to_betaflight_raw_frame(&self) -> [u8; SBUS_FRAME_LENGTH] {
+        self.to_array()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reference frame with sticks at Betaflight's min, center and max values
+    ///
+    /// Synthetic: encoded from [`BETAFLIGHT_CHANNELS`], not captured from a
+    /// receiver.
+    const BETAFLIGHT_FRAME: [u8; SBUS_FRAME_LENGTH] = [
+        0x0F, 0xAD, 0x00, 0xDF, 0xC4, 0xC1, 0xD7, 0x8A, 0x89, 0x83, 0x0F, 0x7C, 0xAD, 0x00, 0xDF,
+        0xC4, 0xC1, 0xD7, 0x8A, 0x89, 0x83, 0x0F, 0x7C, 0x00, 0x00,
+    ];
+
+    const MIN: u16 = BETAFLIGHT_SBUS_MIN;
+    const MID: u16 = BETAFLIGHT_SBUS_MIDPOINT;
+    const MAX: u16 = BETAFLIGHT_SBUS_MAX;
+
+    const BETAFLIGHT_CHANNELS: [u16; 16] = [
+        MIN, MID, MAX, MID, MIN, MAX, MID, MID, MIN, MID, MAX, MID, MIN, MAX, MID, MID,
+    ];
+
+    #[test]
+    fn test_decode_betaflight_frame() {
+        let packet = SbusPacket::from_array(&BETAFLIGHT_FRAME).unwrap();
+        assert_eq!(packet.channels, BETAFLIGHT_CHANNELS);
+        assert!(packet.channels_in_range(BETAFLIGHT_SBUS_MIN, BETAFLIGHT_SBUS_MAX));
+        assert!(!packet.flags.failsafe);
+    }
+
+    #[test]
+    fn test_midpoint_is_1500_us() {
+        // Betaflight's sbusChannelsReadRawRC
+        let to_us = |value: u16| 5 * value / 8 + 880;
+        assert_eq!(to_us(BETAFLIGHT_SBUS_MIDPOINT), 1500);
+    }
+
+    #[test]
+    fn test_encode_betaflight_frame() {
+        let packet = SbusPacket::from_array(&BETAFLIGHT_FRAME).unwrap();
+        assert_eq!(packet.to_betaflight_raw_frame(), BETAFLIGHT_FRAME);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{packet::SbusPacket, CHANNEL_MAX};
+
+/// Worst-case encoded length of a [`BlackboxRcRecord`]: a 5-byte timestamp and
+/// eight 3-byte deltas
+pub const BLACKBOX_RC_RECORD_MAX_LEN: usize = 5 + 8 * 3;
+
+/// RC command record for Betaflight's blackbox log
+///
+/// Holds the change of the first eight channels since the previous record.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BlackboxRcRecord {
+    pub channels: [i16; 8],
+    pub timestamp_us: u32,
+}
+
+impl BlackboxRcRecord {
+    /// Computes the channel deltas from `prev` to `curr`
+    ///
+    /// Channel values above [`CHANNEL_MAX`] are clamped first, so every delta fits
+    /// in an `i16`.
+    pub fn from_sbus_packets(prev: &SbusPacket, curr: &SbusPacket, ts: u32) -> Self {
+        let mut channels = [0i16; 8];
+        channels
+            .iter_mut()
+            .zip(prev.channels.iter().zip(curr.channels.iter()))
+            .for_each(|(delta, (&prev, &curr))| {
+                *delta = curr.min(CHANNEL_MAX) as i16 - prev.min(CHANNEL_MAX) as i16
+            });
+
+        Self {
+            channels,
+            timestamp_us: ts,
+        }
+    }
+
+    /// Encodes the record with blackbox variable-length integers
+    ///
+    /// The timestamp is written as an unsigned variable byte integer, followed by
+    /// the deltas as zig-zag encoded signed variable byte integers.
+    pub fn to_blackbox_bytes(&self) -> heapless::Vec<u8, BLACKBOX_RC_RECORD_MAX_LEN> {
+        let mut bytes = heapless::Vec::new();
+        write_unsigned_vb(&mut bytes, self.timestamp_us);
+        for &delta in &self.channels {
+            write_unsigned_vb(&mut bytes, zig_zag_encode(delta.into()));
+        }
+        bytes
+    }
+}
+
+fn zig_zag_encode(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Writes 7 bits per byte, least significant group first, with the high bit set on
+/// every byte but the last
+fn write_unsigned_vb<const N: usize>(out: &mut heapless::Vec<u8, N>, mut value: u32) {
+    while value > 0x7F {
+        // Capacity is sized for the worst case, pushes cannot fail
+        let _ = out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    let _ = out.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(first: [u16; 8]) -> SbusPacket {
+        let mut packet = SbusPacket::default();
+        packet.channels[..8].copy_from_slice(&first);
+        packet
+    }
+
+    #[test]
+    fn test_blackbox_deltas() {
+        let prev = packet([1000, 1000, 1000, 1000, 0, 2047, 992, 992]);
+        let curr = packet([1001, 999, 1064, 936, 2047, 0, 992, 992]);
+        let record = BlackboxRcRecord::from_sbus_packets(&prev, &curr, 1234);
+        assert_eq!(record.channels, [1, -1, 64, -64, 2047, -2047, 0, 0]);
+        assert_eq!(record.timestamp_us, 1234);
+    }
+
+    #[test]
+    fn test_blackbox_clamps_out_of_range_channels() {
+        let prev = packet([0, u16::MAX, 2048, 992, 992, 992, 992, 992]);
+        let curr = packet([u16::MAX, 0, CHANNEL_MAX, 992, 992, 992, 992, 992]);
+        let record = BlackboxRcRecord::from_sbus_packets(&prev, &curr, 0);
+        assert_eq!(record.channels, [2047, -2047, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_blackbox_sign_encoding() {
+        let record = BlackboxRcRecord {
+            channels: [0, -1, 1, -64, 64, -2047, 2047, i16::MIN],
+            timestamp_us: 300,
+        };
+        assert_eq!(
+            record.to_blackbox_bytes()[..],
+            [
+                0xAC, 0x02, // 300
+                0x00, // 0
+                0x01, // -1
+                0x02, // 1
+                0x7F, // -64
+                0x80, 0x01, // 64
+                0xFD, 0x1F, // -2047
+                0xFE, 0x1F, // 2047
+                0xFF, 0xFF, 0x03, // -32768
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blackbox_worst_case_fits() {
+        let record = BlackboxRcRecord {
+            channels: [i16::MIN; 8],
+            timestamp_us: u32::MAX,
+        };
+        assert_eq!(record.to_blackbox_bytes().len(), BLACKBOX_RC_RECORD_MAX_LEN);
+    }
+}
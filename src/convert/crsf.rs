@@ -0,0 +1,360 @@
+use crate::{
+    crc::crc8_dvb_s2, pack_channels, packet::SbusPacket, ticks_to_signed, ChannelAssignment,
+    SBUS_FRAME_LENGTH,
+};
+
+/// CRSF address of the flight controller
+pub const CRSF_ADDRESS_FLIGHT_CONTROLLER: u8 = 0xC8;
+/// CRSF frame type of packed RC channels
+pub const CRSF_FRAMETYPE_RC_CHANNELS_PACKED: u8 = 0x16;
+/// CRSF frame type of link statistics
+pub const CRSF_FRAMETYPE_LINK_STATISTICS: u8 = 0x14;
+/// CRSF frame type of attitude telemetry
+pub const CRSF_FRAMETYPE_ATTITUDE: u8 = 0x1E;
+/// Angle of a fully deflected stick in [`CrsfAttitude`], π in centiradians
+pub const CRSF_ATTITUDE_MAX_CENTIRAD: i16 = 314;
+
+/// Length of the packed channel payload, identical to the SBUS data bytes
+const CRSF_CHANNELS_PAYLOAD_LENGTH: usize = 22;
+
+/// Header of a CRSF extended frame
+///
+/// `frame_size` counts every byte after itself: type, addresses, payload and CRC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrsfExtHeader {
+    pub device_addr: u8,
+    pub frame_size: u8,
+    pub type_byte: u8,
+    pub src_addr: u8,
+    pub dst_addr: u8,
+}
+
+impl CrsfExtHeader {
+    /// Encoded header length
+    pub const LENGTH: usize = 5;
+
+    /// Encodes the header in wire order: device address, size, type, destination,
+    /// source
+    pub fn to_bytes(&self) -> [u8; Self::LENGTH] {
+        [
+            self.device_addr,
+            self.frame_size,
+            self.type_byte,
+            self.dst_addr,
+            self.src_addr,
+        ]
+    }
+
+    /// Decodes the header from the start of a frame
+    pub fn from_bytes(frame: &[u8]) -> Option<Self> {
+        match *frame {
+            [device_addr, frame_size, type_byte, dst_addr, src_addr, ..] => Some(Self {
+                device_addr,
+                frame_size,
+                type_byte,
+                src_addr,
+                dst_addr,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Payload of a CRSF LINK_STATISTICS frame
+///
+/// RSSI values are dBm magnitudes and SNR values are in dB, as on the wire.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CrsfLinkStats {
+    pub uplink_rssi_ant1: u8,
+    pub uplink_rssi_ant2: u8,
+    pub uplink_link_quality: u8,
+    pub uplink_snr: i8,
+    pub active_antenna: u8,
+    pub rf_mode: u8,
+    pub uplink_tx_power: u8,
+    pub downlink_rssi: u8,
+    pub downlink_link_quality: u8,
+    pub downlink_snr: i8,
+    /// Whether the receiver is in failsafe; not part of the wire format
+    pub rc_failsafe_active: bool,
+}
+
+impl CrsfLinkStats {
+    /// Encoded payload length
+    pub const LENGTH: usize = 10;
+
+    /// Encodes the payload in wire order
+    pub fn to_bytes(&self) -> [u8; Self::LENGTH] {
+        [
+            self.uplink_rssi_ant1,
+            self.uplink_rssi_ant2,
+            self.uplink_link_quality,
+            self.uplink_snr as u8,
+            self.active_antenna,
+            self.rf_mode,
+            self.uplink_tx_power,
+            self.downlink_rssi,
+            self.downlink_link_quality,
+            self.downlink_snr as u8,
+        ]
+    }
+}
+
+/// Attitude angles in centiradians
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CrsfAttitude {
+    pub pitch_rad_x100: i16,
+    pub roll_rad_x100: i16,
+    pub yaw_rad_x100: i16,
+}
+
+/// Maps a channel value to ±[`CRSF_ATTITUDE_MAX_CENTIRAD`]
+fn ticks_to_centirad(ticks: u16) -> i16 {
+    let max = CRSF_ATTITUDE_MAX_CENTIRAD as i32;
+    ticks_to_signed(ticks, max, max) as i16
+}
+
+impl CrsfAttitude {
+    /// Encoded frame length: address, size, type, three angles and CRC
+    pub const LENGTH: usize = 10;
+
+    /// Converts stick channel values to angles, the midpoint being 0 rad
+    pub fn from_sbus_channels(pitch_ch: u16, roll_ch: u16, yaw_ch: u16) -> CrsfAttitude {
+        CrsfAttitude {
+            pitch_rad_x100: ticks_to_centirad(pitch_ch),
+            roll_rad_x100: ticks_to_centirad(roll_ch),
+            yaw_rad_x100: ticks_to_centirad(yaw_ch),
+        }
+    }
+
+    /// Encodes an ATTITUDE frame addressed to the flight controller
+    ///
+    /// On the wire the angles are big-endian in units of 100 µrad, i.e. the
+    /// centiradian values times 100, in pitch, roll, yaw order. Angles beyond
+    /// ±[`CRSF_ATTITUDE_MAX_CENTIRAD`] are clamped. The frame ends with a
+    /// CRC-8/DVB-S2 over the type and payload.
+    pub fn to_crsf_attitude_bytes(&self) -> [u8; Self::LENGTH] {
+        let mut frame = [0u8; Self::LENGTH];
+        frame[0] = CRSF_ADDRESS_FLIGHT_CONTROLLER;
+        frame[1] = (Self::LENGTH - 2) as u8;
+        frame[2] = CRSF_FRAMETYPE_ATTITUDE;
+        for (i, angle) in [self.pitch_rad_x100, self.roll_rad_x100, self.yaw_rad_x100]
+            .into_iter()
+            .enumerate()
+        {
+            let angle = angle.clamp(-CRSF_ATTITUDE_MAX_CENTIRAD, CRSF_ATTITUDE_MAX_CENTIRAD);
+            frame[3 + 2 * i..5 + 2 * i].copy_from_slice(&(angle * 100).to_be_bytes());
+        }
+        frame[Self::LENGTH - 1] = crc8_dvb_s2(&frame[2..Self::LENGTH - 1]);
+        frame
+    }
+}
+
+impl SbusPacket {
+    /// Builds a CRSF extended RC channels frame addressed from `src` to `dst`
+    ///
+    /// The channel payload uses the same 11-bit packing as SBUS, so values are copied
+    /// unchanged. The frame is sent to `dst` (used as the leading device address) and
+    /// ends with a CRC-8/DVB-S2 over the type, addresses and payload. Flags are not
+    /// part of the frame.
+    pub fn to_crsf_extended_frame(&self, src: u8, dst: u8) -> heapless::Vec<u8, 32> {
+        let header = CrsfExtHeader {
+            device_addr: dst,
+            frame_size: (1 + 2 + CRSF_CHANNELS_PAYLOAD_LENGTH + 1) as u8,
+            type_byte: CRSF_FRAMETYPE_RC_CHANNELS_PACKED,
+            src_addr: src,
+            dst_addr: dst,
+        };
+
+        let mut sbus_frame = [0u8; SBUS_FRAME_LENGTH];
+        pack_channels(&mut sbus_frame, &self.channels);
+
+        let mut frame = heapless::Vec::new();
+        // The frame is 28 bytes long, which always fits in the 32-byte buffer
+        let _ = frame.extend_from_slice(&header.to_bytes());
+        let _ = frame.extend_from_slice(&sbus_frame[1..=CRSF_CHANNELS_PAYLOAD_LENGTH]);
+        let _ = frame.push(crc8_dvb_s2(&frame[2..]));
+        frame
+    }
+
+    /// Synthesizes link statistics from the SBUS flags
+    ///
+    /// SBUS carries no RSSI or SNR, so only the uplink link quality is set: 255 for
+    /// a healthy link and 0 when a frame was lost or the receiver is in failsafe.
+    pub fn synthetic_crsf_link_stats(&self) -> CrsfLinkStats {
+        let healthy = !self.flags.frame_lost && !self.flags.failsafe;
+        CrsfLinkStats {
+            uplink_link_quality: if healthy { 255 } else { 0 },
+            rc_failsafe_active: self.flags.failsafe,
+            ..CrsfLinkStats::default()
+        }
+    }
+
+    /// Encodes [`SbusPacket::synthetic_crsf_link_stats`] as a LINK_STATISTICS
+    /// payload
+    pub fn to_crsf_link_stats_bytes(&self) -> [u8; CrsfLinkStats::LENGTH] {
+        self.synthetic_crsf_link_stats().to_bytes()
+    }
+
+    /// Reads the attitude from the stick channels in AETR order (see
+    /// [`ChannelAssignment::AETR`])
+    pub fn to_crsf_attitude(&self) -> CrsfAttitude {
+        let assignment = ChannelAssignment::AETR;
+        CrsfAttitude::from_sbus_channels(
+            self[assignment.pitch],
+            self[assignment.roll],
+            self[assignment.yaw],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{channels_parsing, Flags, CHANNEL_MAX, CHANNEL_MID};
+
+    fn ascending_packet() -> SbusPacket {
+        let mut packet = SbusPacket::default();
+        packet
+            .channels
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = 172 + i as u16 * 109);
+        packet
+    }
+
+    #[test]
+    fn test_crsf_extended_header_fields() {
+        let frame = ascending_packet().to_crsf_extended_frame(0xEA, CRSF_ADDRESS_FLIGHT_CONTROLLER);
+        assert_eq!(frame.len(), 28);
+
+        let header = CrsfExtHeader::from_bytes(&frame).unwrap();
+        assert_eq!(header.device_addr, CRSF_ADDRESS_FLIGHT_CONTROLLER);
+        assert_eq!(header.frame_size as usize, frame.len() - 2);
+        assert_eq!(header.type_byte, CRSF_FRAMETYPE_RC_CHANNELS_PACKED);
+        assert_eq!(header.dst_addr, CRSF_ADDRESS_FLIGHT_CONTROLLER);
+        assert_eq!(header.src_addr, 0xEA);
+        assert_eq!(header.to_bytes(), frame[..CrsfExtHeader::LENGTH]);
+    }
+
+    #[test]
+    fn test_crsf_extended_crc() {
+        let mut frame = ascending_packet().to_crsf_extended_frame(0xEA, 0xC8);
+        let crc = frame.pop().unwrap();
+        assert_eq!(crc, crc8_dvb_s2(&frame[2..]));
+
+        let other = SbusPacket {
+            channels: [CHANNEL_MAX; 16],
+            ..SbusPacket::default()
+        }
+        .to_crsf_extended_frame(0xEA, 0xC8);
+        assert_ne!(other.last(), Some(&crc));
+    }
+
+    #[test]
+    fn test_crsf_extended_channels() {
+        let packet = ascending_packet();
+        let frame = packet.to_crsf_extended_frame(0xEE, 0xC8);
+
+        let mut sbus_frame = [0u8; SBUS_FRAME_LENGTH];
+        sbus_frame[1..=22]
+            .copy_from_slice(&frame[CrsfExtHeader::LENGTH..CrsfExtHeader::LENGTH + 22]);
+        assert_eq!(channels_parsing(&sbus_frame), packet.channels);
+    }
+
+    #[test]
+    fn test_crsf_link_stats() {
+        let cases = [
+            (false, false, 255, false),
+            (true, false, 0, false),
+            (false, true, 0, true),
+            (true, true, 0, true),
+        ];
+        for (frame_lost, failsafe, link_quality, failsafe_active) in cases {
+            let packet = SbusPacket {
+                flags: Flags {
+                    frame_lost,
+                    failsafe,
+                    ..Flags::default()
+                },
+                ..SbusPacket::default()
+            };
+            let stats = packet.synthetic_crsf_link_stats();
+            assert_eq!(stats.uplink_link_quality, link_quality);
+            assert_eq!(stats.rc_failsafe_active, failsafe_active);
+
+            let mut expected = [0u8; CrsfLinkStats::LENGTH];
+            expected[2] = link_quality;
+            assert_eq!(packet.to_crsf_link_stats_bytes(), expected);
+        }
+    }
+
+    #[test]
+    fn test_crsf_link_stats_byte_order() {
+        let stats = CrsfLinkStats {
+            uplink_rssi_ant1: 1,
+            uplink_rssi_ant2: 2,
+            uplink_link_quality: 3,
+            uplink_snr: -4,
+            active_antenna: 5,
+            rf_mode: 6,
+            uplink_tx_power: 7,
+            downlink_rssi: 8,
+            downlink_link_quality: 9,
+            downlink_snr: -10,
+            rc_failsafe_active: true,
+        };
+        assert_eq!(stats.to_bytes(), [1, 2, 3, 0xFC, 5, 6, 7, 8, 9, 0xF6]);
+    }
+
+    #[test]
+    fn test_crsf_attitude_conversion() {
+        let centered = CrsfAttitude::from_sbus_channels(CHANNEL_MID, CHANNEL_MID, CHANNEL_MID);
+        assert_eq!(centered, CrsfAttitude::default());
+
+        let deflected = CrsfAttitude::from_sbus_channels(0, CHANNEL_MAX, 1536);
+        assert_eq!(deflected.pitch_rad_x100, -CRSF_ATTITUDE_MAX_CENTIRAD);
+        assert_eq!(deflected.roll_rad_x100, CRSF_ATTITUDE_MAX_CENTIRAD);
+        assert_eq!(deflected.yaw_rad_x100, 157);
+
+        // Out-of-range values are clamped
+        assert_eq!(
+            CrsfAttitude::from_sbus_channels(u16::MAX, 0, 0).pitch_rad_x100,
+            CRSF_ATTITUDE_MAX_CENTIRAD
+        );
+    }
+
+    #[test]
+    fn test_crsf_attitude_bytes() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = CHANNEL_MAX;
+        let attitude = packet.to_crsf_attitude();
+        assert_eq!(attitude.roll_rad_x100, CRSF_ATTITUDE_MAX_CENTIRAD);
+
+        let frame = attitude.to_crsf_attitude_bytes();
+        assert_eq!(
+            frame[..3],
+            [CRSF_ADDRESS_FLIGHT_CONTROLLER, 8, CRSF_FRAMETYPE_ATTITUDE]
+        );
+        assert_eq!(frame[3..9], [0, 0, 0x7A, 0xA8, 0, 0]);
+        assert_eq!(frame[9], crc8_dvb_s2(&frame[2..9]));
+    }
+
+    #[test]
+    fn test_crsf_attitude_bytes_clamp_fields() {
+        let attitude = CrsfAttitude {
+            pitch_rad_x100: i16::MAX,
+            roll_rad_x100: -328,
+            yaw_rad_x100: i16::MIN,
+        };
+        let frame = attitude.to_crsf_attitude_bytes();
+        let max = (CRSF_ATTITUDE_MAX_CENTIRAD * 100).to_be_bytes();
+        let min = (-CRSF_ATTITUDE_MAX_CENTIRAD * 100).to_be_bytes();
+        assert_eq!(frame[3..5], max);
+        assert_eq!(frame[5..7], min);
+        assert_eq!(frame[7..9], min);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{packet::SbusPacket, CHANNEL_COUNT};
+
+/// Length of the serialized Cyphal RC input payload
+pub const CYPHAL_RC_PAYLOAD_LENGTH: usize = CHANNEL_COUNT * 12 / 8;
+/// Largest value of a `uint12` field
+const CYPHAL_UINT12_MAX: u16 = 0x0FFF;
+
+impl SbusPacket {
+    /// Serializes the channels as the `uint12[16]` payload of a Cyphal RC input
+    /// message
+    ///
+    /// Fields are packed least significant bit first, following the DSDL
+    /// serialization rules, and channel values are carried unscaled. Values above
+    /// 4095 are clamped. The transfer header depends on the transport (CAN, UDP or
+    /// serial) and is left to it; flags are not represented.
+    pub fn to_cyphal_rc_channels(&self) -> [u8; CYPHAL_RC_PAYLOAD_LENGTH] {
+        let mut payload = [0; CYPHAL_RC_PAYLOAD_LENGTH];
+        for (pair, bytes) in self
+            .channels
+            .chunks_exact(2)
+            .zip(payload.chunks_exact_mut(3))
+        {
+            let low = pair[0].min(CYPHAL_UINT12_MAX);
+            let high = pair[1].min(CYPHAL_UINT12_MAX);
+            bytes[0] = low as u8;
+            bytes[1] = (low >> 8) as u8 | (high << 4) as u8;
+            bytes[2] = (high >> 4) as u8;
+        }
+        payload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_cyphal_12_bit_packing() {
+        let mut packet = SbusPacket {
+            channels: [0; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0x123;
+        packet.channels[1] = 0x456;
+        packet.channels[15] = CHANNEL_MAX;
+        let payload = packet.to_cyphal_rc_channels();
+        assert_eq!(payload[..3], [0x23, 0x61, 0x45]);
+        assert_eq!(payload[21..], [0x00, 0xF0, 0x7F]);
+        assert!(payload[3..21].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_cyphal_clamps_to_uint12() {
+        let packet = SbusPacket {
+            channels: [u16::MAX; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        assert_eq!(packet.to_cyphal_rc_channels(), [0xFF; 24]);
+
+        let packet = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        assert_eq!(packet.to_cyphal_rc_channels()[..3], [0x00, 0x04, 0x40]);
+    }
+}
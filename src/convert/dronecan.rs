@@ -0,0 +1,112 @@
+use crate::{packet::SbusPacket, ticks_to_signed};
+
+/// Data type ID of `uavcan.equipment.esc.RawCommand`
+pub const DRONECAN_RAW_COMMAND_DTID: u16 = 1030;
+/// Most ESC setpoints a RawCommand message carries
+pub const DRONECAN_RAW_COMMAND_MAX_ESCS: usize = 20;
+/// Bits of one setpoint in a RawCommand message
+const RAW_COMMAND_BITS: usize = 14;
+
+/// ESC setpoints of a DroneCAN `uavcan.equipment.esc.RawCommand` message
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DronecanRawCommand {
+    /// Setpoints in `-8192..=8191`
+    pub values: heapless::Vec<i16, DRONECAN_RAW_COMMAND_MAX_ESCS>,
+}
+
+impl DronecanRawCommand {
+    /// Serializes the message, prefixed with its data type ID in little-endian
+    ///
+    /// The setpoints follow as 14-bit signed integers packed most significant bit
+    /// first, low byte before high bits as libcanard encodes them. The array is the
+    /// last field, so it has no length prefix.
+    pub fn to_dronecan_bytes(&self) -> heapless::Vec<u8, 42> {
+        let mut bytes = heapless::Vec::new();
+        // Two bytes of prefix and at most 35 bytes of payload always fit
+        let _ = bytes.extend_from_slice(&DRONECAN_RAW_COMMAND_DTID.to_le_bytes());
+        let payload_len = (self.values.len() * RAW_COMMAND_BITS).div_ceil(8);
+        let _ = bytes.resize(2 + payload_len, 0);
+
+        let payload = &mut bytes[2..];
+        let mut bit = 0;
+        for &value in &self.values {
+            let value = value as u16;
+            for (field, len) in [(value & 0xFF, 8), ((value >> 8) & 0x3F, 6)] {
+                for i in (0..len).rev() {
+                    if field & (1 << i) != 0 {
+                        payload[bit / 8] |= 0x80 >> (bit % 8);
+                    }
+                    bit += 1;
+                }
+            }
+        }
+        bytes
+    }
+}
+
+impl SbusPacket {
+    /// Builds a RawCommand with one setpoint per entry of `esc_indices`, taken from
+    /// that channel
+    ///
+    /// Channels are scaled to `-8192..=8191` with [`crate::CHANNEL_MID`] at 0. Indices
+    /// past [`DRONECAN_RAW_COMMAND_MAX_ESCS`] are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is not below 16.
+    pub fn to_dronecan_raw_command(&self, esc_indices: &[usize]) -> DronecanRawCommand {
+        DronecanRawCommand {
+            values: esc_indices
+                .iter()
+                .take(DRONECAN_RAW_COMMAND_MAX_ESCS)
+                .map(|&idx| ticks_to_signed(self.channels[idx], 8192, 8191) as i16)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    fn packet() -> SbusPacket {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[1] = CHANNEL_MAX;
+        packet.channels[2] = 1536;
+        packet
+    }
+
+    #[test]
+    fn test_scaling_and_selection() {
+        let command = packet().to_dronecan_raw_command(&[2, 0, 1, 3, 1]);
+        assert_eq!(command.values, [4100, -8192, 8191, 0, 8191]);
+
+        let all: heapless::Vec<usize, 32> = (0..16).cycle().take(32).collect();
+        let command = packet().to_dronecan_raw_command(&all);
+        assert_eq!(command.values.len(), DRONECAN_RAW_COMMAND_MAX_ESCS);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let command = DronecanRawCommand {
+            values: heapless::Vec::from_slice(&[-8192, 8191, 1]).unwrap(),
+        };
+        let bytes = command.to_dronecan_bytes();
+        assert_eq!(bytes[..2], [0x06, 0x04]);
+        // 0x2000: 00000000 100000, 0x1FFF: 11111111 011111, 1: 00000001 000000
+        assert_eq!(bytes[2..], [0x00, 0x83, 0xFD, 0xF0, 0x10, 0x00]);
+    }
+
+    #[test]
+    fn test_empty_command() {
+        assert_eq!(
+            DronecanRawCommand::default().to_dronecan_bytes(),
+            [0x06, 0x04]
+        );
+    }
+}
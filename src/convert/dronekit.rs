@@ -0,0 +1,149 @@
+use core::fmt::Write;
+
+use crate::{
+    conversion::{ticks_to_us, us_to_ticks},
+    packet::SbusPacket,
+    SbusError, CHANNEL_COUNT, CHANNEL_MID,
+};
+
+/// Worst-case length of a DroneKit RC override JSON object
+pub const DRONEKIT_JSON_MAX_LEN: usize = 183;
+
+impl SbusPacket {
+    /// Formats the channels as a DroneKit RC override dictionary
+    ///
+    /// Produces `{"1": 1500, "2": 1500, ...}` with 1-based channel keys and values in
+    /// microseconds. Flags are not represented. Returns `None` if the output does not
+    /// fit in `N` bytes; `N = DRONEKIT_JSON_MAX_LEN` always fits.
+    pub fn to_dronekit_json<const N: usize>(&self) -> Option<heapless::String<N>> {
+        let mut json = heapless::String::new();
+        json.push('{').ok()?;
+        for (i, &value) in self.channels.iter().enumerate() {
+            if i > 0 {
+                json.push_str(", ").ok()?;
+            }
+            write!(json, "\"{}\": {}", i + 1, ticks_to_us(value)).ok()?;
+        }
+        json.push('}').ok()?;
+        Some(json)
+    }
+
+    /// Parses a DroneKit RC override dictionary
+    ///
+    /// Channels missing from the object are set to [`CHANNEL_MID`] and pulse widths
+    /// outside 1000-2000 µs are clamped. Only flat objects with quoted 1-based integer
+    /// keys and integer values are accepted.
+    pub fn from_dronekit_json(s: &str) -> Result<SbusPacket, SbusError> {
+        let body = s
+            .trim()
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or(SbusError::InvalidFormat)?;
+
+        let mut channels = [CHANNEL_MID; CHANNEL_COUNT];
+        if body.trim().is_empty() {
+            return Ok(SbusPacket {
+                channels,
+                ..SbusPacket::default()
+            });
+        }
+
+        for entry in body.split(',') {
+            let (key, value) = entry.split_once(':').ok_or(SbusError::InvalidFormat)?;
+            let index = key
+                .trim()
+                .strip_prefix('"')
+                .and_then(|k| k.strip_suffix('"'))
+                .and_then(|k| k.parse::<usize>().ok())
+                .and_then(|n| n.checked_sub(1))
+                .filter(|&i| i < CHANNEL_COUNT)
+                .ok_or(SbusError::InvalidFormat)?;
+            let us = value
+                .trim()
+                .parse::<u16>()
+                .map_err(|_| SbusError::InvalidFormat)?;
+            channels[index] = us_to_ticks(us);
+        }
+
+        Ok(SbusPacket {
+            channels,
+            ..SbusPacket::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{conversion::US_MAX, CHANNEL_MAX};
+
+    #[test]
+    fn test_dronekit_keys_are_one_based() {
+        let json = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        }
+        .to_dronekit_json::<DRONEKIT_JSON_MAX_LEN>()
+        .unwrap();
+
+        assert!(json.starts_with("{\"1\": 1500, \"2\": 1500,"));
+        assert!(json.ends_with("\"16\": 1500}"));
+        assert!(!json.contains("\"0\""));
+    }
+
+    #[test]
+    fn test_dronekit_round_trip() {
+        let mut packet = SbusPacket::default();
+        packet
+            .channels
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = us_to_ticks(1000 + i as u16 * 66));
+
+        let json = packet.to_dronekit_json::<DRONEKIT_JSON_MAX_LEN>().unwrap();
+        assert_eq!(SbusPacket::from_dronekit_json(&json), Ok(packet));
+    }
+
+    #[test]
+    fn test_dronekit_worst_case_fits() {
+        let packet = SbusPacket {
+            channels: [CHANNEL_MAX; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        let json = packet.to_dronekit_json::<DRONEKIT_JSON_MAX_LEN>().unwrap();
+        assert_eq!(json.len(), DRONEKIT_JSON_MAX_LEN);
+        assert!(json.contains(&US_MAX.to_string()));
+    }
+
+    #[test]
+    fn test_dronekit_partial_object() {
+        let packet = SbusPacket::from_dronekit_json(r#" { "3" : 1000 } "#).unwrap();
+        assert_eq!(packet.channels[2], 0);
+        assert_eq!(packet.channels[0], CHANNEL_MID);
+        assert_eq!(
+            SbusPacket::from_dronekit_json("{}").unwrap().channels,
+            [CHANNEL_MID; CHANNEL_COUNT]
+        );
+    }
+
+    #[test]
+    fn test_dronekit_invalid_json() {
+        for json in [
+            "",
+            "\"1\": 1500",
+            "{1: 1500}",
+            "{\"0\": 1500}",
+            "{\"17\": 1500}",
+            "{\"1\": }",
+            "{\"1\": 15.5}",
+            "{\"1\" 1500}",
+        ] {
+            assert_eq!(
+                SbusPacket::from_dronekit_json(json),
+                Err(SbusError::InvalidFormat),
+                "{:?} should be rejected",
+                json
+            );
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{packet::SbusPacket, SbusError, SBUS_FRAME_LENGTH};
+
+/// Bits of the flag byte that EdgeTX's SBUS-over-ELRS output may use for its own
+/// inter-frame gap encoding
+///
+/// Standard SBUS leaves these bits unused; only the lower nibble carries flags.
+pub const ELRS_OTX_RESERVED_FLAG_BITS: u8 = 0xF0;
+
+impl SbusPacket {
+    /// Decodes a frame produced by EdgeTX's SBUS-over-ELRS output
+    ///
+    /// Header and footer are validated as usual, while the upper nibble of the flag
+    /// byte ([`ELRS_OTX_RESERVED_FLAG_BITS`]) is ignored whatever its content.
+    ///
+    /// There is no matching encoder: the gap encoding in that nibble is not publicly
+    /// documented, and a frame from [`SbusPacket::to_array`] already decodes here.
+    pub fn from_elrs_otx_frame(buf: &[u8; SBUS_FRAME_LENGTH]) -> Result<SbusPacket, SbusError> {
+        let mut frame = *buf;
+        frame[23] &= !ELRS_OTX_RESERVED_FLAG_BITS;
+        SbusPacket::from_array(&frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+
+    fn packet() -> SbusPacket {
+        SbusPacket {
+            channels: [
+                172, 992, 1811, 992, 172, 992, 1811, 992, 0, 2047, 1, 2046, 992, 992, 992, 992,
+            ],
+            flags: Flags::from_byte(0b0101),
+        }
+    }
+
+    #[test]
+    fn test_elrs_otx_decodes_standard_frame() {
+        let frame = packet().to_array();
+        assert_eq!(frame[23] & ELRS_OTX_RESERVED_FLAG_BITS, 0);
+        assert_eq!(SbusPacket::from_elrs_otx_frame(&frame), Ok(packet()));
+    }
+
+    #[test]
+    fn test_elrs_otx_upper_nibble_ignored() {
+        let mut frame = packet().to_array();
+        for upper in 0..=0x0F {
+            frame[23] = (upper << 4) | 0b0101;
+            assert_eq!(SbusPacket::from_elrs_otx_frame(&frame), Ok(packet()));
+        }
+    }
+
+    #[test]
+    fn test_elrs_otx_validates_header_and_footer() {
+        let mut frame = packet().to_array();
+        frame[0] = 0x0E;
+        assert_eq!(
+            SbusPacket::from_elrs_otx_frame(&frame),
+            Err(SbusError::InvalidHeader(0x0E))
+        );
+        frame[0] = 0x0F;
+        frame[24] = 0x04;
+        assert_eq!(
+            SbusPacket::from_elrs_otx_frame(&frame),
+            Err(SbusError::InvalidFooter(0x04))
+        );
+    }
+}
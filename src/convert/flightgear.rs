@@ -0,0 +1,96 @@
+use core::fmt::Write;
+
+use crate::{packet::SbusPacket, ticks_to_bipolar, ticks_to_unipolar, ChannelAssignment};
+
+/// Length of a FlightGear control line: four values of at most 7 characters,
+/// separated by commas and terminated by a newline
+pub const FLIGHTGEAR_LINE_MAX_LEN: usize = 4 * 7 + 3 + 1;
+
+/// Control inputs for FlightGear
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FlightGearControls {
+    /// `-1.0..=1.0`
+    pub aileron: f32,
+    /// `-1.0..=1.0`
+    pub elevator: f32,
+    /// `-1.0..=1.0`
+    pub rudder: f32,
+    /// `0.0..=1.0`
+    pub throttle: f32,
+}
+
+impl SbusPacket {
+    /// Maps the stick channels to FlightGear controls
+    ///
+    /// Channels 0 to 3 are read in AETR order (see [`ChannelAssignment::AETR`]).
+    pub fn to_flightgear_controls(&self) -> FlightGearControls {
+        let assignment = ChannelAssignment::AETR;
+        FlightGearControls {
+            aileron: ticks_to_bipolar(self[assignment.roll]),
+            elevator: ticks_to_bipolar(self[assignment.pitch]),
+            rudder: ticks_to_bipolar(self[assignment.yaw]),
+            throttle: ticks_to_unipolar(self[assignment.throttle]),
+        }
+    }
+
+    /// Formats the controls as a line for FlightGear's generic input protocol
+    ///
+    /// The line is `aileron,elevator,rudder,throttle` with four decimals and a
+    /// trailing newline, matching an input protocol file that declares the four
+    /// chunks in that order. Returns `None` if the line does not fit in `N` bytes;
+    /// `N = FLIGHTGEAR_LINE_MAX_LEN` always fits.
+    pub fn to_flightgear_socket_line<const N: usize>(&self) -> Option<heapless::String<N>> {
+        let controls = self.to_flightgear_controls();
+        let mut line = heapless::String::new();
+        writeln!(
+            line,
+            "{:.4},{:.4},{:.4},{:.4}",
+            controls.aileron, controls.elevator, controls.rudder, controls.throttle
+        )
+        .ok()?;
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_flightgear_controls() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[1] = CHANNEL_MAX;
+        packet.channels[2] = 0;
+
+        assert_eq!(
+            packet.to_flightgear_controls(),
+            FlightGearControls {
+                aileron: -1.0,
+                elevator: 1.0,
+                rudder: 0.0,
+                throttle: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_flightgear_socket_line() {
+        let mut packet = SbusPacket {
+            channels: [0; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[2] = CHANNEL_MAX;
+        packet.channels[3] = CHANNEL_MID;
+
+        let line = packet
+            .to_flightgear_socket_line::<FLIGHTGEAR_LINE_MAX_LEN>()
+            .unwrap();
+        assert_eq!(line, "-1.0000,-1.0000,0.0000,1.0000\n");
+        assert!(packet.to_flightgear_socket_line::<16>().is_none());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{crc::frsky_checksum, pack_channel_data, packet::SbusPacket, CHANNEL_COUNT};
+
+/// Byte delimiting FPort frames
+pub const FPORT_FRAME_DELIMITER: u8 = 0x7E;
+/// FPort2 frame type of RC channels
+pub const FPORT2_FRAMETYPE_CONTROL: u8 = 0xFF;
+/// Length of an FPort2 control frame including both delimiters
+pub const FPORT2_FRAME_LENGTH: usize = 29;
+
+/// RC channels and flags of an FPort2 control frame
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FPort2Frame {
+    pub rc: [u16; CHANNEL_COUNT],
+    /// Same bit layout as the SBUS flag byte
+    pub flags: u8,
+}
+
+impl FPort2Frame {
+    /// Takes over the channels and flags of a packet
+    ///
+    /// FPort2 uses the same 11-bit channel range and flag bits as SBUS, so values
+    /// are copied unchanged.
+    pub fn from_sbus_packet(p: &SbusPacket) -> FPort2Frame {
+        FPort2Frame {
+            rc: p.channels,
+            flags: p.flags.to_byte(),
+        }
+    }
+
+    /// Encodes the control frame
+    ///
+    /// The frame is [`FPORT_FRAME_DELIMITER`], the length of the control data, the
+    /// type [`FPORT2_FRAMETYPE_CONTROL`], the channels packed as in SBUS, the flags,
+    /// an RSSI byte, the FrSky checksum over type, channels, flags and RSSI, and a
+    /// closing delimiter. SBUS carries no RSSI, so that byte is 0. Byte stuffing of
+    /// `0x7D`/`0x7E` is left to the transport.
+    pub fn to_bytes(&self) -> [u8; FPORT2_FRAME_LENGTH] {
+        let mut frame = [0u8; FPORT2_FRAME_LENGTH];
+        frame[0] = FPORT_FRAME_DELIMITER;
+        // Channel data, flags and RSSI
+        frame[1] = (FPORT2_FRAME_LENGTH - 5) as u8;
+        frame[2] = FPORT2_FRAMETYPE_CONTROL;
+        frame[3..25].copy_from_slice(&pack_channel_data(&self.rc));
+        frame[25] = self.flags;
+        frame[26] = 0;
+        frame[27] = frsky_checksum(&frame[2..27]);
+        frame[28] = FPORT_FRAME_DELIMITER;
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{unpack_channel_data, Flags, CHANNEL_MAX};
+
+    fn packet() -> SbusPacket {
+        SbusPacket {
+            channels: core::array::from_fn(|i| i as u16 * 131),
+            flags: Flags::from_byte(0b1100),
+        }
+    }
+
+    #[test]
+    fn test_frame_structure() {
+        let frame = FPort2Frame::from_sbus_packet(&packet()).to_bytes();
+        assert_eq!(frame.len(), 29);
+        assert_eq!(frame[0], FPORT_FRAME_DELIMITER);
+        assert_eq!(frame[1], 24);
+        assert_eq!(frame[2], FPORT2_FRAMETYPE_CONTROL);
+        assert_eq!(
+            unpack_channel_data(frame[3..25].try_into().unwrap()),
+            packet().channels
+        );
+        assert_eq!(frame[25], 0b1100);
+        assert_eq!(frame[26], 0);
+        assert_eq!(frame[28], FPORT_FRAME_DELIMITER);
+    }
+
+    #[test]
+    fn test_checksum() {
+        let frame = FPort2Frame::from_sbus_packet(&packet()).to_bytes();
+        // The checksum completes the covered bytes to 0xFF
+        let sum = frame[2..28].iter().fold(0u16, |sum, &byte| {
+            let sum = sum + byte as u16;
+            (sum + (sum >> 8)) & 0xFF
+        });
+        assert_eq!(sum, 0xFF);
+
+        let other = FPort2Frame {
+            rc: [CHANNEL_MAX; CHANNEL_COUNT],
+            flags: 0,
+        };
+        assert_ne!(other.to_bytes()[27], frame[27]);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{crc::frsky_checksum, packet::SbusPacket, CHANNEL_COUNT, CHANNEL_MID};
+
+/// App ID of the sensor carrying channel 0; channel `i` uses this plus `i`
+pub const TARANIS_CHANNEL_APP_ID_BASE: u16 = 0x5200;
+/// S.Port frame type of a sensor data packet
+pub const FRSKY_SPORT_DATA_FRAME: u8 = 0x10;
+/// Length of an S.Port sensor data packet: frame type, app ID, value and CRC
+pub const FRSKY_SPORT_PACKET_LENGTH: usize = 8;
+
+/// Custom sensor value shown on a FrSky Taranis
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TaranisSensor {
+    pub app_id: u16,
+    pub value: i32,
+}
+
+impl TaranisSensor {
+    /// Serializes the sensor as an S.Port data packet
+    ///
+    /// The packet holds the frame type, the little-endian app ID and value, and the
+    /// S.Port checksum. Byte stuffing of `0x7D`/`0x7E` is left to the transport.
+    pub fn to_frsky_sport_bytes(&self) -> [u8; FRSKY_SPORT_PACKET_LENGTH] {
+        let mut packet = [0u8; FRSKY_SPORT_PACKET_LENGTH];
+        packet[0] = FRSKY_SPORT_DATA_FRAME;
+        packet[1..3].copy_from_slice(&self.app_id.to_le_bytes());
+        packet[3..7].copy_from_slice(&self.value.to_le_bytes());
+        packet[7] = frsky_checksum(&packet[..7]);
+        packet
+    }
+}
+
+impl SbusPacket {
+    /// Maps every channel to a custom sensor
+    ///
+    /// Channel `i` becomes app ID `TARANIS_CHANNEL_APP_ID_BASE + i` with its value
+    /// shifted so that [`CHANNEL_MID`] reads as 0.
+    pub fn to_taranis_channel_sensors(&self) -> [TaranisSensor; CHANNEL_COUNT] {
+        let mut sensors = [TaranisSensor::default(); CHANNEL_COUNT];
+        for (i, (sensor, &value)) in sensors.iter_mut().zip(&self.channels).enumerate() {
+            *sensor = TaranisSensor {
+                app_id: TARANIS_CHANNEL_APP_ID_BASE + i as u16,
+                value: value as i32 - CHANNEL_MID as i32,
+            };
+        }
+        sensors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHANNEL_MAX;
+
+    #[test]
+    fn test_taranis_sensors() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[15] = CHANNEL_MAX;
+
+        let sensors = packet.to_taranis_channel_sensors();
+        for (i, sensor) in sensors.iter().enumerate() {
+            assert_eq!(sensor.app_id, 0x5200 + i as u16);
+        }
+        assert_eq!(sensors[0].value, -1024);
+        assert_eq!(sensors[1].value, 0);
+        assert_eq!(sensors[15].value, 1023);
+    }
+
+    #[test]
+    fn test_sport_bytes() {
+        let sensor = TaranisSensor {
+            app_id: 0x5200,
+            value: 0,
+        };
+        assert_eq!(
+            sensor.to_frsky_sport_bytes(),
+            [0x10, 0x00, 0x52, 0x00, 0x00, 0x00, 0x00, 0x9D]
+        );
+
+        let sensor = TaranisSensor {
+            app_id: 0x520F,
+            value: -1024,
+        };
+        let bytes = sensor.to_frsky_sport_bytes();
+        assert_eq!(bytes[1..7], [0x0F, 0x52, 0x00, 0xFC, 0xFF, 0xFF]);
+        // 0x10 + 0x0F + 0x52 + 0xFC + 3 * 0xFF with carries folded in is 0x6E
+        assert_eq!(bytes[7], 0xFF - 0x6E);
+    }
+}
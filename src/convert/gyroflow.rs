@@ -0,0 +1,71 @@
+use core::fmt::Write;
+
+use crate::packet::SbusPacket;
+
+/// Worst-case length of a GyroFlow CSV line: a 20-digit timestamp followed by 16
+/// comma-separated 5-digit channel values
+pub const GYROFLOW_LINE_MAX_LEN: usize = 20 + 16 * 6;
+
+impl SbusPacket {
+    /// Formats the packet as a GyroFlow RC input CSV line
+    ///
+    /// The columns are `timestamp_us,ch0,...,ch15` with raw, unscaled channel
+    /// values and no trailing newline. Returns `None` if the line does not fit in
+    /// `N` bytes; `N = GYROFLOW_LINE_MAX_LEN` always fits.
+    pub fn to_gyroflow_csv_line<const N: usize>(
+        &self,
+        timestamp_us: u64,
+    ) -> Option<heapless::String<N>> {
+        let mut line = heapless::String::new();
+        write!(line, "{}", timestamp_us).ok()?;
+        for value in self.channels {
+            write!(line, ",{}", value).ok()?;
+        }
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gyroflow_columns() {
+        let mut packet = SbusPacket::default();
+        packet
+            .channels
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = i as u16 * 100);
+
+        let line = packet
+            .to_gyroflow_csv_line::<GYROFLOW_LINE_MAX_LEN>(123_456)
+            .unwrap();
+        assert_eq!(
+            line,
+            "123456,0,100,200,300,400,500,600,700,800,900,1000,1100,1200,1300,1400,1500"
+        );
+        let mut columns = line.split(',');
+        assert_eq!(columns.next(), Some("123456"));
+        assert_eq!(columns.count(), 16);
+    }
+
+    #[test]
+    fn test_gyroflow_worst_case_fits() {
+        let packet = SbusPacket {
+            channels: [u16::MAX; 16],
+            ..SbusPacket::default()
+        };
+        let line = packet
+            .to_gyroflow_csv_line::<GYROFLOW_LINE_MAX_LEN>(u64::MAX)
+            .unwrap();
+        assert_eq!(line.len(), GYROFLOW_LINE_MAX_LEN);
+    }
+
+    #[test]
+    fn test_gyroflow_capacity_too_small() {
+        assert!(SbusPacket::default()
+            .to_gyroflow_csv_line::<16>(0)
+            .is_none());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::packet::SbusPacket;
+
+/// Duration of the rumble requested for each packet
+///
+/// Longer than a few frame periods so the effect stays on while the link is
+/// degraded, without lingering once it recovers.
+pub const HAPTIC_EFFECT_DURATION_MS: u16 = 100;
+
+/// Force-feedback effect for a game controller, derived from the link state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HapticEffect {
+    /// Healthy link
+    None,
+    /// Frames are being lost
+    WeakRumble { duration_ms: u16 },
+    /// The receiver is in failsafe
+    StrongRumble { duration_ms: u16 },
+}
+
+impl SbusPacket {
+    /// Returns the rumble effect for the link state, failsafe taking precedence
+    /// over a lost frame
+    pub fn to_haptic_effect(&self) -> HapticEffect {
+        if self.flags.failsafe {
+            HapticEffect::StrongRumble {
+                duration_ms: HAPTIC_EFFECT_DURATION_MS,
+            }
+        } else if self.flags.frame_lost {
+            HapticEffect::WeakRumble {
+                duration_ms: HAPTIC_EFFECT_DURATION_MS,
+            }
+        } else {
+            HapticEffect::None
+        }
+    }
+
+    /// Rumble intensity: 0 for a healthy link, 50 for a lost frame and 100 in
+    /// failsafe
+    pub fn haptic_intensity_percent(&self) -> u8 {
+        match self.to_haptic_effect() {
+            HapticEffect::None => 0,
+            HapticEffect::WeakRumble { .. } => 50,
+            HapticEffect::StrongRumble { .. } => 100,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+
+    fn packet(frame_lost: bool, failsafe: bool) -> SbusPacket {
+        SbusPacket {
+            flags: Flags {
+                frame_lost,
+                failsafe,
+                ..Flags::default()
+            },
+            ..SbusPacket::default()
+        }
+    }
+
+    #[test]
+    fn test_link_states() {
+        let weak = HapticEffect::WeakRumble {
+            duration_ms: HAPTIC_EFFECT_DURATION_MS,
+        };
+        let strong = HapticEffect::StrongRumble {
+            duration_ms: HAPTIC_EFFECT_DURATION_MS,
+        };
+        let cases = [
+            (false, false, HapticEffect::None, 0),
+            (true, false, weak, 50),
+            (false, true, strong, 100),
+            (true, true, strong, 100),
+        ];
+        for (frame_lost, failsafe, effect, intensity) in cases {
+            let packet = packet(frame_lost, failsafe);
+            assert_eq!(packet.to_haptic_effect(), effect);
+            assert_eq!(packet.haptic_intensity_percent(), intensity);
+        }
+    }
+}
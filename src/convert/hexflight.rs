@@ -0,0 +1,96 @@
+use crate::{packet::SbusPacket, SbusError, CHANNEL_COUNT, CHANNEL_MAX};
+
+/// Magic bytes opening a HexFlight channel datagram
+pub const HEXFLIGHT_MAGIC: [u8; 2] = [0xAB, 0xCD];
+/// Length of a HexFlight channel datagram: magic and 16 channels
+pub const HEXFLIGHT_DATAGRAM_LENGTH: usize = 2 + 2 * CHANNEL_COUNT;
+
+impl SbusPacket {
+    /// Encodes the channels as a HexFlight simulator UDP datagram
+    ///
+    /// The datagram is [`HEXFLIGHT_MAGIC`] followed by the raw channel values as
+    /// little-endian `u16`. Flags are not part of the format.
+    pub fn to_hexflight_udp(&self) -> [u8; HEXFLIGHT_DATAGRAM_LENGTH] {
+        let mut datagram = [0u8; HEXFLIGHT_DATAGRAM_LENGTH];
+        datagram[..2].copy_from_slice(&HEXFLIGHT_MAGIC);
+        for (i, value) in self.channels.iter().enumerate() {
+            datagram[2 + 2 * i..4 + 2 * i].copy_from_slice(&value.to_le_bytes());
+        }
+        datagram
+    }
+
+    /// Decodes a HexFlight simulator UDP datagram
+    ///
+    /// Returns `SbusError::InvalidHeader` with the first mismatching byte if the
+    /// magic is wrong, and `SbusError::InvalidFormat` if a channel is above
+    /// [`CHANNEL_MAX`]. Flags are left cleared.
+    pub fn from_hexflight_udp(
+        data: &[u8; HEXFLIGHT_DATAGRAM_LENGTH],
+    ) -> Result<SbusPacket, SbusError> {
+        if let Some((&byte, _)) = data.iter().zip(&HEXFLIGHT_MAGIC).find(|(a, b)| a != b) {
+            return Err(SbusError::InvalidHeader(byte));
+        }
+        let mut packet = SbusPacket::default();
+        for (i, value) in packet.channels.iter_mut().enumerate() {
+            *value = u16::from_le_bytes([data[2 + 2 * i], data[3 + 2 * i]]);
+            if *value > CHANNEL_MAX {
+                return Err(SbusError::InvalidFormat);
+            }
+        }
+        Ok(packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet() -> SbusPacket {
+        SbusPacket {
+            channels: core::array::from_fn(|i| i as u16 * 136 + 7),
+            ..SbusPacket::default()
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        let datagram = packet().to_hexflight_udp();
+        assert_eq!(datagram[..2], [0xAB, 0xCD]);
+        // Channel 0 is 7, channel 15 is 2047
+        assert_eq!(datagram[2..4], [0x07, 0x00]);
+        assert_eq!(datagram[32..34], [0xFF, 0x07]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(
+            SbusPacket::from_hexflight_udp(&packet().to_hexflight_udp()),
+            Ok(packet())
+        );
+        let extremes = SbusPacket {
+            channels: core::array::from_fn(|i| if i % 2 == 0 { 0 } else { CHANNEL_MAX }),
+            ..SbusPacket::default()
+        };
+        assert_eq!(
+            SbusPacket::from_hexflight_udp(&extremes.to_hexflight_udp()),
+            Ok(extremes)
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_datagrams() {
+        let mut datagram = packet().to_hexflight_udp();
+        datagram[1] = 0xCE;
+        assert_eq!(
+            SbusPacket::from_hexflight_udp(&datagram),
+            Err(SbusError::InvalidHeader(0xCE))
+        );
+
+        let mut datagram = packet().to_hexflight_udp();
+        datagram[5] = 0x08;
+        assert_eq!(
+            SbusPacket::from_hexflight_udp(&datagram),
+            Err(SbusError::InvalidFormat)
+        );
+    }
+}
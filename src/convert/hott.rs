@@ -0,0 +1,147 @@
+use crate::{
+    crc::crc16_ccitt,
+    packet::{Flags, SbusPacket},
+    ticks_to_us, us_to_ticks, SbusError, CHANNEL_COUNT,
+};
+
+/// First byte of a Graupner HoTT SUMD frame
+pub const SUMD_HEADER: u8 = 0xA8;
+/// SUMD status of a frame with live channel data
+pub const SUMD_STATUS_LIVE: u8 = 0x01;
+/// SUMD status of a frame carrying the receiver's failsafe values
+pub const SUMD_STATUS_FAILSAFE: u8 = 0x81;
+/// Length of a 16-channel SUMD frame: header, status, channel count, channels and
+/// CRC
+pub const SUMD_FRAME_LENGTH: usize = 3 + 2 * CHANNEL_COUNT + 2;
+
+/// SUMD channel values are in units of 1/8 µs
+const SUMD_COUNTS_PER_US: u16 = 8;
+
+impl SbusPacket {
+    /// Encodes the packet as a 16-channel SUMD frame
+    ///
+    /// Channels are converted with [`ticks_to_us`] and sent big-endian in 1/8 µs
+    /// timer counts, so 1000 to 2000 µs become `0x1F40` to `0x3E80`. A packet in
+    /// failsafe is sent with the failsafe status. The frame ends with a big-endian
+    /// CRC-16/CCITT over all preceding bytes.
+    pub fn to_sumd_frame(&self) -> [u8; SUMD_FRAME_LENGTH] {
+        let mut frame = [0u8; SUMD_FRAME_LENGTH];
+        frame[0] = SUMD_HEADER;
+        frame[1] = if self.flags.failsafe {
+            SUMD_STATUS_FAILSAFE
+        } else {
+            SUMD_STATUS_LIVE
+        };
+        frame[2] = CHANNEL_COUNT as u8;
+        for (i, &value) in self.channels.iter().enumerate() {
+            let counts = ticks_to_us(value) * SUMD_COUNTS_PER_US;
+            frame[3 + 2 * i..5 + 2 * i].copy_from_slice(&counts.to_be_bytes());
+        }
+        let crc = crc16_ccitt(&frame[..SUMD_FRAME_LENGTH - 2]);
+        frame[SUMD_FRAME_LENGTH - 2..].copy_from_slice(&crc.to_be_bytes());
+        frame
+    }
+
+    /// Decodes a 16-channel SUMD frame
+    ///
+    /// Pulse widths outside 1000 to 2000 µs are clamped. Returns
+    /// `SbusError::InvalidHeader` for a wrong header byte and
+    /// `SbusError::InvalidFormat` for an unknown status, a channel count other than
+    /// 16 or a CRC mismatch.
+    pub fn from_sumd_frame(data: &[u8; SUMD_FRAME_LENGTH]) -> Result<SbusPacket, SbusError> {
+        if data[0] != SUMD_HEADER {
+            return Err(SbusError::InvalidHeader(data[0]));
+        }
+        let crc = u16::from_be_bytes([data[SUMD_FRAME_LENGTH - 2], data[SUMD_FRAME_LENGTH - 1]]);
+        let failsafe = match data[1] {
+            SUMD_STATUS_LIVE => false,
+            SUMD_STATUS_FAILSAFE => true,
+            _ => return Err(SbusError::InvalidFormat),
+        };
+        if data[2] as usize != CHANNEL_COUNT || crc16_ccitt(&data[..SUMD_FRAME_LENGTH - 2]) != crc {
+            return Err(SbusError::InvalidFormat);
+        }
+
+        let mut packet = SbusPacket {
+            flags: Flags {
+                failsafe,
+                ..Flags::default()
+            },
+            ..SbusPacket::default()
+        };
+        for (i, value) in packet.channels.iter_mut().enumerate() {
+            let counts = u16::from_be_bytes([data[3 + 2 * i], data[4 + 2 * i]]);
+            *value = us_to_ticks(counts / SUMD_COUNTS_PER_US);
+        }
+        Ok(packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    fn packet() -> SbusPacket {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[1] = CHANNEL_MAX;
+        packet
+    }
+
+    #[test]
+    fn test_timer_count_scaling() {
+        let frame = packet().to_sumd_frame();
+        assert_eq!(frame[..3], [SUMD_HEADER, SUMD_STATUS_LIVE, 16]);
+        // 1000, 2000 and 1500 µs
+        assert_eq!(frame[3..9], [0x1F, 0x40, 0x3E, 0x80, 0x2E, 0xE0]);
+    }
+
+    #[test]
+    fn test_crc() {
+        let frame = packet().to_sumd_frame();
+        let crc = crc16_ccitt(&frame[..SUMD_FRAME_LENGTH - 2]);
+        assert_eq!(frame[SUMD_FRAME_LENGTH - 2..], crc.to_be_bytes());
+
+        let mut corrupted = frame;
+        corrupted[10] ^= 0x01;
+        assert_eq!(
+            SbusPacket::from_sumd_frame(&corrupted),
+            Err(SbusError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut packet = packet();
+        assert_eq!(
+            SbusPacket::from_sumd_frame(&packet.to_sumd_frame()),
+            Ok(packet)
+        );
+
+        packet.flags.failsafe = true;
+        let frame = packet.to_sumd_frame();
+        assert_eq!(frame[1], SUMD_STATUS_FAILSAFE);
+        assert_eq!(SbusPacket::from_sumd_frame(&frame), Ok(packet));
+    }
+
+    #[test]
+    fn test_rejects_invalid_frames() {
+        let mut frame = packet().to_sumd_frame();
+        frame[0] = 0xA9;
+        assert_eq!(
+            SbusPacket::from_sumd_frame(&frame),
+            Err(SbusError::InvalidHeader(0xA9))
+        );
+
+        let mut frame = packet().to_sumd_frame();
+        frame[2] = 12;
+        assert_eq!(
+            SbusPacket::from_sumd_frame(&frame),
+            Err(SbusError::InvalidFormat)
+        );
+    }
+}
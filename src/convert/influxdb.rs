@@ -0,0 +1,88 @@
+use core::fmt::Write;
+
+use crate::packet::SbusPacket;
+
+impl SbusPacket {
+    /// Formats the packet as an InfluxDB line protocol point
+    ///
+    /// Produces `sbus ch0=1024i,...,ch15=1024i,frame_lost=0i,failsafe=0i <ts>` with
+    /// raw channel values as integer fields and the timestamp in nanoseconds.
+    /// Commas and spaces in `measurement` are escaped. Returns `None` if
+    /// `measurement` is empty or the output does not fit in `N` bytes.
+    pub fn to_influxdb_line<const N: usize>(
+        &self,
+        measurement: &str,
+        timestamp_ns: u64,
+    ) -> Option<heapless::String<N>> {
+        if measurement.is_empty() {
+            return None;
+        }
+        let mut line = heapless::String::new();
+        for c in measurement.chars() {
+            if c == ',' || c == ' ' {
+                line.push('\\').ok()?;
+            }
+            line.push(c).ok()?;
+        }
+        for (i, value) in self.channels.iter().enumerate() {
+            let separator = if i == 0 { ' ' } else { ',' };
+            write!(line, "{}ch{}={}i", separator, i, value).ok()?;
+        }
+        write!(
+            line,
+            ",frame_lost={}i,failsafe={}i {}",
+            self.flags.frame_lost as u8, self.flags.failsafe as u8, timestamp_ns
+        )
+        .ok()?;
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Flags, CHANNEL_MID};
+
+    #[test]
+    fn test_influxdb_line_format() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            flags: Flags::from_byte(0b1000),
+        };
+        packet.channels[15] = 172;
+        let line = packet
+            .to_influxdb_line::<256>("sbus_channels", 1_700_000_000_000_000_000)
+            .unwrap();
+
+        // Measurement, field set and timestamp are separated by single spaces
+        let parts: Vec<&str> = line.split(' ').collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], "sbus_channels");
+        assert_eq!(parts[2], "1700000000000000000");
+
+        let fields: Vec<(&str, &str)> = parts[1]
+            .split(',')
+            .map(|field| field.split_once('=').unwrap())
+            .collect();
+        assert_eq!(fields.len(), 18);
+        assert_eq!(fields[0], ("ch0", "1024i"));
+        assert_eq!(fields[15], ("ch15", "172i"));
+        assert_eq!(fields[16], ("frame_lost", "0i"));
+        assert_eq!(fields[17], ("failsafe", "1i"));
+    }
+
+    #[test]
+    fn test_influxdb_measurement_escaping() {
+        let line = SbusPacket::default()
+            .to_influxdb_line::<256>("rc link,rx", 0)
+            .unwrap();
+        assert!(line.starts_with("rc\\ link\\,rx ch0=0i,"));
+        assert!(line.ends_with(" 0"));
+
+        assert_eq!(SbusPacket::default().to_influxdb_line::<256>("", 0), None);
+        assert_eq!(
+            SbusPacket::default().to_influxdb_line::<32>("sbus", 0),
+            None
+        );
+    }
+}
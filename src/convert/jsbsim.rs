@@ -0,0 +1,114 @@
+use core::fmt::Write;
+
+use crate::{packet::SbusPacket, ticks_to_bipolar, ticks_to_unipolar, ChannelAssignment};
+
+/// Length of the `set` commands for all four properties at their longest, with
+/// negative control surface values
+pub const JSBSIM_LINE_MAX_LEN: usize = 132;
+
+impl SbusPacket {
+    /// Maps the stick channels to normalized JSBSim flight control properties
+    ///
+    /// Channels 0 to 3 are read in AETR order (see [`ChannelAssignment::AETR`]).
+    /// Aileron, elevator and rudder are in `-1.0..=1.0`, throttle in `0.0..=1.0`.
+    pub fn to_jsbsim_properties(&self) -> [(&'static str, f64); 4] {
+        let assignment = ChannelAssignment::AETR;
+        [
+            (
+                "fcs/aileron-cmd-norm",
+                ticks_to_bipolar(self[assignment.roll]) as f64,
+            ),
+            (
+                "fcs/elevator-cmd-norm",
+                ticks_to_bipolar(self[assignment.pitch]) as f64,
+            ),
+            (
+                "fcs/throttle-cmd-norm",
+                ticks_to_unipolar(self[assignment.throttle]) as f64,
+            ),
+            (
+                "fcs/rudder-cmd-norm",
+                ticks_to_bipolar(self[assignment.yaw]) as f64,
+            ),
+        ]
+    }
+
+    /// Formats the properties as `set <property> <value>` commands for JSBSim's
+    /// socket interface, one per line, with four decimals
+    ///
+    /// Returns `None` if the commands do not fit in `N` bytes;
+    /// `N = JSBSIM_LINE_MAX_LEN` always fits.
+    pub fn to_jsbsim_socket_line<const N: usize>(&self) -> Option<heapless::String<N>> {
+        let mut line = heapless::String::new();
+        for (property, value) in self.to_jsbsim_properties() {
+            writeln!(line, "set {} {:.4}", property, value).ok()?;
+        }
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_jsbsim_properties() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[1] = CHANNEL_MAX;
+        packet.channels[2] = CHANNEL_MAX;
+
+        assert_eq!(
+            packet.to_jsbsim_properties(),
+            [
+                ("fcs/aileron-cmd-norm", -1.0),
+                ("fcs/elevator-cmd-norm", 1.0),
+                ("fcs/throttle-cmd-norm", 1.0),
+                ("fcs/rudder-cmd-norm", 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jsbsim_value_ranges() {
+        for value in [0, 1, CHANNEL_MID, CHANNEL_MAX - 1, CHANNEL_MAX] {
+            let packet = SbusPacket {
+                channels: [value; 16],
+                ..SbusPacket::default()
+            };
+            let properties = packet.to_jsbsim_properties();
+            for (property, value) in properties {
+                let min = if property == "fcs/throttle-cmd-norm" {
+                    0.0
+                } else {
+                    -1.0
+                };
+                assert!((min..=1.0).contains(&value), "{} = {}", property, value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jsbsim_socket_line() {
+        let packet = SbusPacket {
+            channels: [0; 16],
+            ..SbusPacket::default()
+        };
+        let line = packet
+            .to_jsbsim_socket_line::<JSBSIM_LINE_MAX_LEN>()
+            .unwrap();
+        assert_eq!(line.len(), JSBSIM_LINE_MAX_LEN);
+        assert_eq!(
+            line,
+            "set fcs/aileron-cmd-norm -1.0000\n\
+             set fcs/elevator-cmd-norm -1.0000\n\
+             set fcs/throttle-cmd-norm 0.0000\n\
+             set fcs/rudder-cmd-norm -1.0000\n"
+        );
+        assert!(packet.to_jsbsim_socket_line::<64>().is_none());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{packet::SbusPacket, ticks_to_us};
+
+/// MSP v1 request preamble
+pub const MSP_REQUEST_HEADER: [u8; 3] = *b"$M<";
+/// MSP command setting the RC channels
+pub const MSP_SET_RAW_RC: u8 = 200;
+/// Channels carried by an `MSP_SET_RAW_RC` request
+pub const MSP_RC_CHANNEL_COUNT: usize = 8;
+/// Length of an `MSP_SET_RAW_RC` request: header, size, command, payload and
+/// checksum
+pub const MSP_SET_RAW_RC_FRAME_LENGTH: usize = 3 + 1 + 1 + 2 * MSP_RC_CHANNEL_COUNT + 1;
+
+impl SbusPacket {
+    /// Encodes channels 0 to 7 as an MSP v1 `MSP_SET_RAW_RC` request
+    ///
+    /// Channels are converted with [`ticks_to_us`] and sent as little-endian
+    /// microseconds. The checksum is the XOR of the size, command and payload bytes.
+    pub fn to_msp_rc_raw_frame(&self) -> [u8; MSP_SET_RAW_RC_FRAME_LENGTH] {
+        let mut frame = [0u8; MSP_SET_RAW_RC_FRAME_LENGTH];
+        frame[..3].copy_from_slice(&MSP_REQUEST_HEADER);
+        frame[3] = (2 * MSP_RC_CHANNEL_COUNT) as u8;
+        frame[4] = MSP_SET_RAW_RC;
+        for (i, &value) in self.channels[..MSP_RC_CHANNEL_COUNT].iter().enumerate() {
+            frame[5 + 2 * i..7 + 2 * i].copy_from_slice(&ticks_to_us(value).to_le_bytes());
+        }
+        frame[MSP_SET_RAW_RC_FRAME_LENGTH - 1] = frame[3..MSP_SET_RAW_RC_FRAME_LENGTH - 1]
+            .iter()
+            .fold(0, |checksum, &byte| checksum ^ byte);
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_msp_set_raw_rc() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[1] = CHANNEL_MAX;
+        packet.channels[8] = 0;
+
+        let frame = packet.to_msp_rc_raw_frame();
+        assert_eq!(frame[..5], [b'$', b'M', b'<', 16, 200]);
+        // 1000, 2000, then 1500 µs; channel 8 is not sent
+        assert_eq!(frame[5..9], [0xE8, 0x03, 0xD0, 0x07]);
+        for i in 2..8 {
+            assert_eq!(frame[5 + 2 * i..7 + 2 * i], [0xDC, 0x05]);
+        }
+
+        // 16 ^ 200 ^ 0xE8 ^ 0x03 ^ 0xD0 ^ 0x07, the six 1500 µs channels cancel out
+        let checksum = 16 ^ 200 ^ 0xE8 ^ 0x03 ^ 0xD0 ^ 0x07;
+        assert_eq!(frame[MSP_SET_RAW_RC_FRAME_LENGTH - 1], checksum);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{packet::SbusPacket, Flags, CHANNEL_MID};
+
+/// Mask of the channels taken from the right-hand stream
+const RIGHT_CHANNELS: u16 = 0xFF00;
+
+impl SbusPacket {
+    /// Combines two 8-channel streams of a dual-receiver setup into one packet
+    ///
+    /// Channels 0 to 7 are taken from `left` and 8 to 15 from `right`. A flag is set
+    /// if it is set in either packet.
+    pub fn from_multilink(left: &SbusPacket, right: &SbusPacket) -> SbusPacket {
+        let flags = Flags::from_byte(left.flags.to_byte() | right.flags.to_byte());
+        left.merged_with_flags(right, RIGHT_CHANNELS, flags)
+    }
+
+    /// Splits the packet into the two streams combined by
+    /// [`SbusPacket::from_multilink`]
+    ///
+    /// Channels keep their position; the channels not carried by a stream are set
+    /// to [`CHANNEL_MID`]. Both streams carry the flags of this packet.
+    pub fn split_multilink(&self) -> (SbusPacket, SbusPacket) {
+        let neutral = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            flags: self.flags,
+        };
+        (
+            neutral.merged(self, !RIGHT_CHANNELS),
+            neutral.merged(self, RIGHT_CHANNELS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multilink_join() {
+        let left = SbusPacket {
+            channels: [100; 16],
+            flags: Flags::from_byte(0b0001),
+        };
+        let right = SbusPacket {
+            channels: [200; 16],
+            flags: Flags::from_byte(0b1000),
+        };
+        let joined = SbusPacket::from_multilink(&left, &right);
+        assert_eq!(joined.channels[..8], [100; 8]);
+        assert_eq!(joined.channels[8..], [200; 8]);
+        assert_eq!(joined.flags.to_byte(), 0b1001);
+    }
+
+    #[test]
+    fn test_multilink_split_join_round_trip() {
+        let mut packet = SbusPacket {
+            flags: Flags::from_byte(0b0110),
+            ..SbusPacket::default()
+        };
+        packet
+            .channels
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, value)| *value = 172 + i as u16 * 100);
+
+        let (left, right) = packet.split_multilink();
+        assert_eq!(left.channels[..8], packet.channels[..8]);
+        assert_eq!(left.channels[8..], [CHANNEL_MID; 8]);
+        assert_eq!(right.channels[..8], [CHANNEL_MID; 8]);
+        assert_eq!(right.channels[8..], packet.channels[8..]);
+        assert_eq!(SbusPacket::from_multilink(&left, &right), packet);
+    }
+}
@@ -0,0 +1,100 @@
+use core::f32::consts::FRAC_PI_2;
+
+use crate::{packet::SbusPacket, ticks_to_bipolar, ChannelAssignment};
+
+/// Fraction of the stick travel around the center that commands no motion
+pub const NED_DEAD_BAND: f32 = 0.05;
+/// Yaw rate at full rudder deflection, in rad/s
+pub const NED_MAX_YAW_RATE_RAD_S: f32 = FRAC_PI_2;
+
+/// Velocity command in the North-East-Down frame
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct NedVelocity {
+    pub north_ms: f32,
+    pub east_ms: f32,
+    pub down_ms: f32,
+    pub yaw_rate_rad_s: f32,
+}
+
+/// Applies [`NED_DEAD_BAND`] to a value in `-1.0..=1.0`, rescaling the rest of the
+/// travel so the output still reaches ±1.0
+fn dead_band(value: f32) -> f32 {
+    if value > NED_DEAD_BAND {
+        (value - NED_DEAD_BAND) / (1.0 - NED_DEAD_BAND)
+    } else if value < -NED_DEAD_BAND {
+        (value + NED_DEAD_BAND) / (1.0 - NED_DEAD_BAND)
+    } else {
+        0.0
+    }
+}
+
+impl SbusPacket {
+    /// Maps the sticks to a velocity command for position-hold flight modes
+    ///
+    /// Channels 0 to 3 are read in AETR order (see [`ChannelAssignment::AETR`]) and
+    /// centered on the midpoint: pitch commands north, roll east, throttle up
+    /// (negative down) and yaw the yaw rate. Velocities reach ±`max_velocity_ms`
+    /// and the yaw rate ±[`NED_MAX_YAW_RATE_RAD_S`] at full deflection, after the
+    /// [`NED_DEAD_BAND`] around the center.
+    pub fn to_ned_velocity_commands(&self, max_velocity_ms: f32) -> NedVelocity {
+        let assignment = ChannelAssignment::AETR;
+        let stick = |channel| dead_band(ticks_to_bipolar(self[channel]));
+        NedVelocity {
+            north_ms: stick(assignment.pitch) * max_velocity_ms,
+            east_ms: stick(assignment.roll) * max_velocity_ms,
+            down_ms: -stick(assignment.throttle) * max_velocity_ms,
+            yaw_rate_rad_s: stick(assignment.yaw) * NED_MAX_YAW_RATE_RAD_S,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    fn packet(sticks: [u16; 4]) -> SbusPacket {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[..4].copy_from_slice(&sticks);
+        packet
+    }
+
+    #[test]
+    fn test_zero_at_midpoint() {
+        let velocity = packet([CHANNEL_MID; 4]).to_ned_velocity_commands(10.0);
+        assert_eq!(velocity, NedVelocity::default());
+
+        // Within the dead band
+        let velocity = packet([
+            CHANNEL_MID + 40,
+            CHANNEL_MID - 40,
+            CHANNEL_MID + 10,
+            CHANNEL_MID,
+        ])
+        .to_ned_velocity_commands(10.0);
+        assert_eq!(velocity, NedVelocity::default());
+    }
+
+    #[test]
+    fn test_max_at_extremes() {
+        let velocity = packet([CHANNEL_MAX, 0, CHANNEL_MAX, 0]).to_ned_velocity_commands(5.0);
+        assert_eq!(
+            velocity,
+            NedVelocity {
+                north_ms: -5.0,
+                east_ms: 5.0,
+                down_ms: -5.0,
+                yaw_rate_rad_s: -NED_MAX_YAW_RATE_RAD_S,
+            }
+        );
+
+        let velocity = packet([0, CHANNEL_MAX, 0, CHANNEL_MAX]).to_ned_velocity_commands(5.0);
+        assert_eq!(velocity.north_ms, 5.0);
+        assert_eq!(velocity.east_ms, -5.0);
+        assert_eq!(velocity.down_ms, 5.0);
+        assert_eq!(velocity.yaw_rate_rad_s, NED_MAX_YAW_RATE_RAD_S);
+    }
+}
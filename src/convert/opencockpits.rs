@@ -0,0 +1,60 @@
+use crate::{packet::SbusPacket, CHANNEL_MAX};
+
+/// Report ID of the IOCards analog axes input report
+pub const IOCARDS_AXES_REPORT_ID: u8 = 0x01;
+/// Length of the IOCards analog axes input report, including the report ID
+pub const IOCARDS_HID_REPORT_LENGTH: usize = 17;
+/// Number of analog axes in the report
+const IOCARDS_AXES: usize = 8;
+
+impl SbusPacket {
+    /// Encodes channels 0-7 as an OpenCockpits IOCards HID input report
+    ///
+    /// The report ID is followed by 8 big-endian axes scaling `0..=CHANNEL_MAX` onto
+    /// `0..=65535`. Values above [`CHANNEL_MAX`] are clamped.
+    pub fn to_iocards_hid_report(&self) -> [u8; IOCARDS_HID_REPORT_LENGTH] {
+        let mut report = [0; IOCARDS_HID_REPORT_LENGTH];
+        report[0] = IOCARDS_AXES_REPORT_ID;
+        for (&value, axis) in self.channels[..IOCARDS_AXES]
+            .iter()
+            .zip(report[1..].chunks_exact_mut(2))
+        {
+            let max = CHANNEL_MAX as u32;
+            let scaled = (value.min(CHANNEL_MAX) as u32 * u16::MAX as u32 + max / 2) / max;
+            axis.copy_from_slice(&(scaled as u16).to_be_bytes());
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHANNEL_MID;
+
+    #[test]
+    fn test_iocards_report_layout() {
+        let mut packet = SbusPacket::default();
+        packet.channels[1] = CHANNEL_MAX;
+        packet.channels[8] = CHANNEL_MAX;
+        let report = packet.to_iocards_hid_report();
+        assert_eq!(report[0], IOCARDS_AXES_REPORT_ID);
+        assert_eq!(report[1..3], [0x00, 0x00]);
+        assert_eq!(report[3..5], [0xFF, 0xFF]);
+        assert!(report[5..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_iocards_midpoint() {
+        let packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        let report = packet.to_iocards_hid_report();
+        for axis in report[1..].chunks_exact(2) {
+            let value = u16::from_be_bytes([axis[0], axis[1]]);
+            assert!(value.abs_diff(32768) <= 32, "{}", value);
+        }
+        assert_eq!(report[1..3], 32784u16.to_be_bytes());
+    }
+}
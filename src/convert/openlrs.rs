@@ -0,0 +1,64 @@
+use crate::{packet::SbusPacket, SbusError, SBUS_FRAME_LENGTH, SBUS_HEADER};
+
+/// Header byte of the modified SBUS output of OpenLRS receivers
+pub const OPENLRS_HEADER: u8 = 0x0E;
+
+impl SbusPacket {
+    /// Decodes a frame from an OpenLRS receiver
+    ///
+    /// Accepts [`OPENLRS_HEADER`] as well as the standard SBUS header; the rest of
+    /// the frame is decoded as regular SBUS.
+    pub fn from_openlrs(buf: &[u8; SBUS_FRAME_LENGTH]) -> Result<SbusPacket, SbusError> {
+        let mut frame = *buf;
+        if frame[0] == OPENLRS_HEADER {
+            frame[0] = SBUS_HEADER;
+        }
+        SbusPacket::from_array(&frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+
+    fn packet() -> SbusPacket {
+        SbusPacket {
+            channels: core::array::from_fn(|i| 100 + i as u16 * 123),
+            flags: Flags::from_byte(0b1000),
+        }
+    }
+
+    #[test]
+    fn test_openlrs_header() {
+        let mut frame = packet().to_array();
+        frame[0] = OPENLRS_HEADER;
+        assert_eq!(SbusPacket::from_openlrs(&frame), Ok(packet()));
+        assert_eq!(
+            SbusPacket::from_array(&frame),
+            Err(SbusError::InvalidHeader(OPENLRS_HEADER))
+        );
+    }
+
+    #[test]
+    fn test_openlrs_accepts_sbus_header() {
+        let frame = packet().to_array();
+        assert_eq!(SbusPacket::from_openlrs(&frame), Ok(packet()));
+    }
+
+    #[test]
+    fn test_openlrs_rejects_other_headers() {
+        let mut frame = packet().to_array();
+        frame[0] = 0x0D;
+        assert_eq!(
+            SbusPacket::from_openlrs(&frame),
+            Err(SbusError::InvalidHeader(0x0D))
+        );
+        frame[0] = OPENLRS_HEADER;
+        frame[24] = 0x04;
+        assert_eq!(
+            SbusPacket::from_openlrs(&frame),
+            Err(SbusError::InvalidFooter(0x04))
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{packet::SbusPacket, ticks_to_us};
+
+/// Number of RC input channels in OpenPilot and LibrePilot
+pub const OPENPILOT_CHANNEL_COUNT: usize = 12;
+
+impl SbusPacket {
+    /// Converts the first [`OPENPILOT_CHANNEL_COUNT`] channels to OpenPilot RC input
+    /// pulse widths in microseconds
+    ///
+    /// Channels map onto `1000..=2000` µs as with [`ticks_to_us`]. The digital
+    /// channels `d1` and `d2` are not part of the output.
+    pub fn to_openpilot_rc(&self) -> [u16; OPENPILOT_CHANNEL_COUNT] {
+        let mut rc = [0; OPENPILOT_CHANNEL_COUNT];
+        for (us, &ticks) in rc.iter_mut().zip(&self.channels) {
+            *us = ticks_to_us(ticks);
+        }
+        rc
+    }
+
+    /// Returns `0x01` if failsafe is active, `0x00` otherwise
+    pub fn to_openpilot_failsafe_state(&self) -> u8 {
+        self.flags.failsafe as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Flags, CHANNEL_MAX, CHANNEL_MID, US_MAX, US_MIN};
+
+    #[test]
+    fn test_openpilot_rc_mapping() {
+        let mut channels = [CHANNEL_MID; 16];
+        channels[0] = 0;
+        channels[11] = CHANNEL_MAX;
+        channels[12] = 0;
+        let packet = SbusPacket {
+            channels,
+            flags: Flags::from_byte(0),
+        };
+
+        let rc = packet.to_openpilot_rc();
+        assert_eq!(rc[0], US_MIN);
+        assert_eq!(rc[1..11], [1500; 10]);
+        assert_eq!(rc[11], US_MAX);
+    }
+
+    #[test]
+    fn test_openpilot_excludes_digital_channels() {
+        let packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            flags: Flags::from_byte(0b0011),
+        };
+        assert_eq!(packet.to_openpilot_rc(), [1500; OPENPILOT_CHANNEL_COUNT]);
+        assert_eq!(packet.to_openpilot_failsafe_state(), 0x00);
+    }
+
+    #[test]
+    fn test_openpilot_failsafe_state() {
+        let packet = SbusPacket {
+            flags: Flags::from_byte(0b1000),
+            ..SbusPacket::default()
+        };
+        assert_eq!(packet.to_openpilot_failsafe_state(), 0x01);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{packet::SbusPacket, ticks_to_signed};
+
+/// Sensor ID of the telemetry value carrying channel 0; channel `i` uses this plus
+/// `i`
+pub const OPENTX_CHANNEL_SENSOR_ID_BASE: u16 = 0x5200;
+/// Sensor type tag of a signed integer value
+pub const OPENTX_SENSOR_TYPE_SIGNED_INT: u8 = 0x01;
+/// Length of a `setTelemetryValue` payload
+pub const OPENTX_TELEMETRY_PAYLOAD_LENGTH: usize = 16;
+/// Value of a fully deflected channel, matching the -1024..=1024 range OpenTX and
+/// EdgeTX use for channel outputs; [`crate::CHANNEL_MID`] reads as 0
+pub const OPENTX_CHANNEL_RANGE: i32 = 1024;
+
+impl SbusPacket {
+    /// Builds one `setTelemetryValue` payload per channel for OpenTX/EdgeTX Lua
+    /// scripts
+    ///
+    /// Payload `i` describes channel `i`:
+    ///
+    /// | Bytes  | Content                                                  |
+    /// |--------|----------------------------------------------------------|
+    /// | 0-1    | sensor ID `OPENTX_CHANNEL_SENSOR_ID_BASE + i`, little-endian |
+    /// | 2      | subcategory, the channel index                           |
+    /// | 3      | sensor type, [`OPENTX_SENSOR_TYPE_SIGNED_INT`]           |
+    /// | 4-7    | value scaled to ±[`OPENTX_CHANNEL_RANGE`], little-endian |
+    /// | 8-15   | reserved, zero                                           |
+    ///
+    /// Only the first `N` channels are included if `N` is below 16.
+    pub fn to_lua_set_telemetry_calls<const N: usize>(
+        &self,
+    ) -> heapless::Vec<[u8; OPENTX_TELEMETRY_PAYLOAD_LENGTH], N> {
+        self.channels
+            .iter()
+            .enumerate()
+            .take(N)
+            .map(|(i, &value)| {
+                let mut payload = [0u8; OPENTX_TELEMETRY_PAYLOAD_LENGTH];
+                let sensor_id = OPENTX_CHANNEL_SENSOR_ID_BASE + i as u16;
+                payload[0..2].copy_from_slice(&sensor_id.to_le_bytes());
+                payload[2] = i as u8;
+                payload[3] = OPENTX_SENSOR_TYPE_SIGNED_INT;
+                payload[4..8].copy_from_slice(
+                    &ticks_to_signed(value, OPENTX_CHANNEL_RANGE, OPENTX_CHANNEL_RANGE)
+                        .to_le_bytes(),
+                );
+                payload
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_COUNT, CHANNEL_MAX, CHANNEL_MID};
+
+    fn packet() -> SbusPacket {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        packet.channels[1] = 0;
+        packet.channels[2] = CHANNEL_MAX;
+        packet.channels[3] = 1536;
+        packet
+    }
+
+    #[test]
+    fn test_sensor_ids() {
+        let payloads = packet().to_lua_set_telemetry_calls::<16>();
+        assert_eq!(payloads.len(), CHANNEL_COUNT);
+        for (i, payload) in payloads.iter().enumerate() {
+            let sensor_id = u16::from_le_bytes([payload[0], payload[1]]);
+            assert_eq!(sensor_id, OPENTX_CHANNEL_SENSOR_ID_BASE + i as u16);
+            assert_eq!(payload[2], i as u8);
+            assert_eq!(payload[3], OPENTX_SENSOR_TYPE_SIGNED_INT);
+            assert_eq!(payload[8..], [0; 8]);
+        }
+    }
+
+    #[test]
+    fn test_value_encoding() {
+        let payloads = packet().to_lua_set_telemetry_calls::<4>();
+        assert_eq!(payloads.len(), 4);
+        let values: heapless::Vec<i32, 4> = payloads
+            .iter()
+            .map(|p| i32::from_le_bytes(p[4..8].try_into().unwrap()))
+            .collect();
+        assert_eq!(values, [0, -1024, 1024, 513]);
+    }
+}
@@ -0,0 +1,131 @@
+use core::fmt::Write;
+
+use crate::{
+    channel::ChannelAssignment,
+    conversion::{
+        ticks_to_bipolar, ticks_to_signed, ticks_to_unipolar, ticks_to_us, US_MAX, US_MIN,
+    },
+    packet::SbusPacket,
+    CHANNEL_COUNT,
+};
+
+/// Full-scale value of the MAVLink `MANUAL_CONTROL` axes
+const QGC_MANUAL_CONTROL_SCALE: i32 = 1000;
+
+// The throttle axis reuses the pulse width conversion, which has the same span
+const _: () = assert!((US_MAX - US_MIN) as i32 == QGC_MANUAL_CONTROL_SCALE);
+
+/// Worst-case length of a QGroundControl joystick JSON object
+pub const QGC_JOYSTICK_JSON_MAX_LEN: usize = 47;
+
+impl SbusPacket {
+    /// Converts the channels to QGroundControl's normalized joystick axes
+    ///
+    /// Every channel is mapped to `-1.0..=1.0` with the midpoint at `0.0`, except
+    /// the throttle of [`ChannelAssignment::AETR`] on channel 2, which is mapped to
+    /// `0.0..=1.0`.
+    pub fn to_qgc_joystick_axes(&self) -> [f32; CHANNEL_COUNT] {
+        let throttle = ChannelAssignment::AETR.throttle;
+        let mut axes = self.channels.map(ticks_to_bipolar);
+        axes[throttle.index()] = ticks_to_unipolar(self[throttle]);
+        axes
+    }
+
+    /// Formats the sticks as the axes of a MAVLink `MANUAL_CONTROL` message
+    ///
+    /// QGroundControl sends joystick input to the autopilot in this message, with
+    /// pitch `x`, roll `y` and yaw `r` in `-1000..=1000` and throttle `z` in
+    /// `0..=1000`. Produces `{"x": 0, "y": 0, "z": 500, "r": 0}` from channels 0-3
+    /// in AETR order (see [`ChannelAssignment::AETR`]). Returns `None` if the
+    /// output does not fit in `N` bytes; `N = QGC_JOYSTICK_JSON_MAX_LEN` always
+    /// fits.
+    pub fn to_qgc_joystick_json<const N: usize>(&self) -> Option<heapless::String<N>> {
+        let assignment = ChannelAssignment::AETR;
+        let axis = |channel| {
+            ticks_to_signed(
+                self[channel],
+                QGC_MANUAL_CONTROL_SCALE,
+                QGC_MANUAL_CONTROL_SCALE,
+            )
+        };
+        let throttle = ticks_to_us(self[assignment.throttle]) - US_MIN;
+
+        let mut json = heapless::String::new();
+        write!(
+            json,
+            "{{\"x\": {}, \"y\": {}, \"z\": {}, \"r\": {}}}",
+            axis(assignment.pitch),
+            axis(assignment.roll),
+            throttle,
+            axis(assignment.yaw)
+        )
+        .ok()?;
+        Some(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_qgc_axes() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        let axes = packet.to_qgc_joystick_axes();
+        assert_eq!(axes[0], 0.0);
+        assert!((axes[2] - 0.5).abs() < 0.001);
+
+        packet.channels[0] = 0;
+        packet.channels[2] = 0;
+        packet.channels[3] = CHANNEL_MAX;
+        let axes = packet.to_qgc_joystick_axes();
+        assert_eq!(axes[0], -1.0);
+        assert_eq!(axes[2], 0.0);
+        assert_eq!(axes[3], 1.0);
+        assert_eq!(axes[15], 0.0);
+    }
+
+    #[test]
+    fn test_qgc_json_axes() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        let json = packet.to_qgc_joystick_json::<QGC_JOYSTICK_JSON_MAX_LEN>();
+        assert_eq!(json.unwrap(), "{\"x\": 0, \"y\": 0, \"z\": 500, \"r\": 0}");
+
+        packet.channels[0] = CHANNEL_MAX;
+        packet.channels[1] = 0;
+        packet.channels[2] = CHANNEL_MAX;
+        let json = packet.to_qgc_joystick_json::<QGC_JOYSTICK_JSON_MAX_LEN>();
+        assert_eq!(
+            json.unwrap(),
+            "{\"x\": -1000, \"y\": 1000, \"z\": 1000, \"r\": 0}"
+        );
+    }
+
+    #[test]
+    fn test_qgc_json_worst_case_fits() {
+        let packet = SbusPacket {
+            channels: [0; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        let json = packet
+            .to_qgc_joystick_json::<QGC_JOYSTICK_JSON_MAX_LEN>()
+            .unwrap();
+        assert_eq!(json, "{\"x\": -1000, \"y\": -1000, \"z\": 0, \"r\": -1000}");
+        let packet = SbusPacket {
+            channels: [0, 0, CHANNEL_MAX, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            ..SbusPacket::default()
+        };
+        let json = packet
+            .to_qgc_joystick_json::<QGC_JOYSTICK_JSON_MAX_LEN>()
+            .unwrap();
+        assert_eq!(json.len(), QGC_JOYSTICK_JSON_MAX_LEN);
+        assert_eq!(packet.to_qgc_joystick_json::<46>(), None);
+    }
+}
@@ -0,0 +1,71 @@
+use core::fmt::Write;
+
+use crate::{packet::SbusPacket, soft_uart::SOFT_UART_FRAME_BITS};
+
+/// Samples per frame at one sample per bit, i.e. a 100 kHz sample rate
+pub const SIGROK_SAMPLES_PER_FRAME: usize = SOFT_UART_FRAME_BITS;
+
+impl SbusPacket {
+    /// Renders the frame as the logic levels of an SBUS line in Sigrok CSV format
+    ///
+    /// Writes one `sample,level` row per bit of [`SbusPacket::to_raw_bits`],
+    /// numbering samples from `sample_index`. The bits are inverted, as on the SBUS
+    /// wire, so the idle line is low. Import into PulseView with a sample rate of
+    /// 100 kHz and the column formats `-,l`. Returns `None` if the output does not
+    /// fit in `N` bytes.
+    pub fn to_sigrok_csv_line<const N: usize>(
+        &self,
+        sample_index: u64,
+    ) -> Option<heapless::String<N>> {
+        let mut csv = heapless::String::new();
+        for (sample, bit) in (sample_index..).zip(self.to_raw_bits()) {
+            writeln!(csv, "{},{}", sample, !bit as u8).ok()?;
+        }
+        Some(csv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(csv: &str) -> Vec<u8> {
+        csv.lines()
+            .map(|line| line.split_once(',').unwrap().1.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sigrok_columns() {
+        let csv = SbusPacket::default()
+            .to_sigrok_csv_line::<4096>(1000)
+            .unwrap();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), SIGROK_SAMPLES_PER_FRAME);
+        for (i, row) in rows.iter().enumerate() {
+            let columns: Vec<&str> = row.split(',').collect();
+            assert_eq!(columns.len(), 2);
+            assert_eq!(columns[0], (1000 + i).to_string());
+            assert!(columns[1] == "0" || columns[1] == "1");
+        }
+        assert!(csv.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_sigrok_inverted_8e2() {
+        let csv = SbusPacket::default().to_sigrok_csv_line::<4096>(0).unwrap();
+        let levels = levels(&csv);
+        // Header 0x0F has four ones, so even parity is 0; every level is inverted
+        assert_eq!(levels[..12], [1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0]);
+        // Footer 0x00: start bit, eight zeros and parity 0 high, stop bits low
+        assert_eq!(
+            levels[SIGROK_SAMPLES_PER_FRAME - 12..],
+            [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_sigrok_capacity() {
+        assert_eq!(SbusPacket::default().to_sigrok_csv_line::<64>(0), None);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{packet::SbusPacket, ticks_to_us, CHANNEL_COUNT};
+
+/// Length of an RC input frame for ArduPilot SITL: sequence number, timestamp and
+/// 16 channels
+pub const SITL_FRAME_LENGTH: usize = 4 + 8 + 2 * CHANNEL_COUNT;
+
+impl SbusPacket {
+    /// Encodes the packet as a binary RC input frame for ArduPilot SITL
+    ///
+    /// The frame holds `seq`, `time_usec` and the 16 channels as pulse widths in
+    /// microseconds (see [`ticks_to_us`]), all big-endian.
+    pub fn to_sitl_socket_frame(&self, seq: u32, time_usec: u64) -> [u8; SITL_FRAME_LENGTH] {
+        let mut frame = [0u8; SITL_FRAME_LENGTH];
+        frame[..4].copy_from_slice(&seq.to_be_bytes());
+        frame[4..12].copy_from_slice(&time_usec.to_be_bytes());
+        for (chunk, &ticks) in frame[12..].chunks_exact_mut(2).zip(&self.channels) {
+            chunk.copy_from_slice(&ticks_to_us(ticks).to_be_bytes());
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_sitl_frame_layout() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[15] = CHANNEL_MAX;
+
+        let frame = packet.to_sitl_socket_frame(0x0102_0304, 0x1122_3344_5566_7788);
+        assert_eq!(frame.len(), 44);
+        assert_eq!(frame[..4], [0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(
+            frame[4..12],
+            [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]
+        );
+        // 1000, 1500 and 2000 µs
+        assert_eq!(frame[12..14], [0x03, 0xE8]);
+        assert_eq!(frame[14..16], [0x05, 0xDC]);
+        assert_eq!(frame[42..44], [0x07, 0xD0]);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{crc::crc16_ccitt, packet::SbusPacket, ticks_to_us, CHANNEL_COUNT};
+
+/// SRXL v1 header of a 12-channel frame
+pub const SRXL_V1_HEADER_12: u8 = 0xA1;
+/// SRXL v1 header of a 16-channel frame
+pub const SRXL_V1_HEADER_16: u8 = 0xA2;
+/// Largest SRXL channel value, 2200 µs
+pub const SRXL_VALUE_MAX: u16 = 0x0FFF;
+/// Longest SRXL v1 frame: header, 16 channels and CRC
+pub const SRXL_V1_MAX_FRAME_LENGTH: usize = 1 + 2 * CHANNEL_COUNT + 2;
+
+/// Pulse width of an SRXL value of 0
+const SRXL_US_MIN: u32 = 800;
+/// Pulse width span of the SRXL value range
+const SRXL_US_SPAN: u32 = 1400;
+
+/// Number of channels carried by an SRXL v1 frame
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SrxlChannels {
+    Twelve,
+    #[default]
+    Sixteen,
+}
+
+impl SrxlChannels {
+    pub const fn count(self) -> usize {
+        match self {
+            SrxlChannels::Twelve => 12,
+            SrxlChannels::Sixteen => 16,
+        }
+    }
+
+    pub const fn header(self) -> u8 {
+        match self {
+            SrxlChannels::Twelve => SRXL_V1_HEADER_12,
+            SrxlChannels::Sixteen => SRXL_V1_HEADER_16,
+        }
+    }
+}
+
+/// Channel values of an SRXL v1 frame
+///
+/// Values span `0..=SRXL_VALUE_MAX` for 800 to 2200 µs, so 1500 µs is `0x800`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SrxlV1Frame {
+    pub channels: SrxlChannels,
+    /// Only the first `channels.count()` values are sent
+    pub values: [u16; CHANNEL_COUNT],
+}
+
+impl SrxlV1Frame {
+    /// Converts the channels of a packet to a 16-channel frame
+    ///
+    /// Channels are converted to their pulse width with [`ticks_to_us`] and then to
+    /// the SRXL scale, so the SBUS range covers 1000 to 2000 µs.
+    pub fn from_sbus_packet(p: &SbusPacket) -> SrxlV1Frame {
+        SrxlV1Frame {
+            channels: SrxlChannels::Sixteen,
+            values: p.channels.map(|ticks| {
+                let us = ticks_to_us(ticks) as u32;
+                (((us - SRXL_US_MIN) * SRXL_VALUE_MAX as u32 + SRXL_US_SPAN / 2) / SRXL_US_SPAN)
+                    as u16
+            }),
+        }
+    }
+
+    /// Encodes the frame: header, big-endian channel values and a big-endian
+    /// CRC-16/CCITT over header and values
+    ///
+    /// A 12-channel frame is 27 bytes long and a 16-channel frame 35 bytes.
+    pub fn to_bytes(&self) -> heapless::Vec<u8, SRXL_V1_MAX_FRAME_LENGTH> {
+        let mut frame = heapless::Vec::new();
+        // At most 35 bytes, which always fits
+        let _ = frame.push(self.channels.header());
+        for value in &self.values[..self.channels.count()] {
+            let _ = frame.extend_from_slice(&value.min(&SRXL_VALUE_MAX).to_be_bytes());
+        }
+        let crc = crc16_ccitt(&frame);
+        let _ = frame.extend_from_slice(&crc.to_be_bytes());
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    fn packet() -> SbusPacket {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[1] = CHANNEL_MAX;
+        packet
+    }
+
+    #[test]
+    fn test_value_scaling() {
+        let frame = SrxlV1Frame::from_sbus_packet(&packet());
+        // 1000 µs, 2000 µs and 1500 µs
+        assert_eq!(frame.values[..3], [585, 3510, 0x800]);
+    }
+
+    #[test]
+    fn test_sixteen_channel_frame() {
+        let bytes = SrxlV1Frame::from_sbus_packet(&packet()).to_bytes();
+        assert_eq!(bytes.len(), 35);
+        assert_eq!(bytes[0], SRXL_V1_HEADER_16);
+        assert_eq!(bytes[1..5], [0x02, 0x49, 0x0D, 0xB6]);
+        assert_eq!(bytes[31..33], [0x08, 0x00]);
+        assert_eq!(crc16_ccitt(&bytes[..33]).to_be_bytes(), bytes[33..]);
+    }
+
+    #[test]
+    fn test_twelve_channel_frame() {
+        let frame = SrxlV1Frame {
+            channels: SrxlChannels::Twelve,
+            ..SrxlV1Frame::from_sbus_packet(&packet())
+        };
+        let bytes = frame.to_bytes();
+        assert_eq!(bytes.len(), 27);
+        assert_eq!(bytes[0], SRXL_V1_HEADER_12);
+        assert_eq!(bytes[23..25], [0x08, 0x00]);
+        assert_eq!(crc16_ccitt(&bytes[..25]).to_be_bytes(), bytes[25..]);
+    }
+}
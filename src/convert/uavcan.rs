@@ -0,0 +1,62 @@
+use crate::{packet::SbusPacket, ticks_to_bipolar, ticks_to_unipolar, ChannelId, CHANNEL_COUNT};
+
+impl SbusPacket {
+    /// Converts the channels to the normalized range of the DroneCAN
+    /// `uavcan.equipment.rc.RCInput` message
+    ///
+    /// Every channel is mapped to `-1.0..=1.0` with the midpoint at `0.0`, except
+    /// `throttle`, if given, which is mapped to `0.0..=1.0`.
+    pub fn to_uavcan_rc_normalized(&self, throttle: Option<ChannelId>) -> [f32; CHANNEL_COUNT] {
+        let mut normalized = self.channels.map(ticks_to_bipolar);
+        if let Some(throttle) = throttle {
+            normalized[throttle.index()] = ticks_to_unipolar(self.channels[throttle.index()]);
+        }
+        normalized
+    }
+
+    /// Resolution of the channel values carried in the RC input message
+    pub const fn to_uavcan_rc_bits_per_channel() -> u8 {
+        SbusPacket::channel_resolution_bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_uavcan_midpoint_and_bounds() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        assert_eq!(packet.to_uavcan_rc_normalized(None), [0.0; 16]);
+
+        packet.channels[0] = 0;
+        packet.channels[1] = CHANNEL_MAX;
+        let normalized = packet.to_uavcan_rc_normalized(None);
+        assert_eq!(normalized[0], -1.0);
+        assert_eq!(normalized[1], 1.0);
+    }
+
+    #[test]
+    fn test_uavcan_throttle_unipolar() {
+        let throttle = ChannelId::new(2).unwrap();
+        let mut packet = SbusPacket {
+            channels: [0; 16],
+            ..SbusPacket::default()
+        };
+        let normalized = packet.to_uavcan_rc_normalized(Some(throttle));
+        assert_eq!(normalized[2], 0.0);
+        assert_eq!(normalized[3], -1.0);
+
+        packet.channels[2] = CHANNEL_MAX;
+        assert_eq!(packet.to_uavcan_rc_normalized(Some(throttle))[2], 1.0);
+    }
+
+    #[test]
+    fn test_uavcan_bits_per_channel() {
+        assert_eq!(SbusPacket::to_uavcan_rc_bits_per_channel(), 11);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{packet::SbusPacket, CHANNEL_MAX, CHANNEL_MID};
+
+/// Channel carrying the VTX band and channel selection
+pub const VTX_BAND_CHANNEL_INDEX: usize = 4;
+/// Channel carrying the VTX power level
+pub const VTX_POWER_CHANNEL_INDEX: usize = 5;
+/// Number of VTX bands, numbered from 1
+pub const VTX_BAND_COUNT: u8 = 5;
+/// Number of channels per VTX band, numbered from 1
+pub const VTX_CHANNELS_PER_BAND: u8 = 8;
+/// Number of VTX power levels, numbered from 1
+pub const VTX_POWER_LEVELS: u8 = 5;
+
+/// VTX band, channel and power selected through Betaflight's channel assignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BetaflightVtxControl {
+    pub band: u8,
+    pub channel: u8,
+    pub power: u8,
+}
+
+/// Index of the equal-width slot of the 11-bit range `value` falls into
+const fn slot(value: u16, slots: u8) -> u8 {
+    let value = if value > CHANNEL_MAX {
+        CHANNEL_MAX
+    } else {
+        value
+    };
+    (value as u32 * slots as u32 / (CHANNEL_MAX as u32 + 1)) as u8
+}
+
+/// Channel value in the middle of a slot, the inverse of [`slot`]
+const fn slot_center(slot: u8, slots: u8) -> u16 {
+    ((2 * slot as u32 + 1) * (CHANNEL_MAX as u32 + 1) / (2 * slots as u32)) as u16
+}
+
+/// Converts a 1-based selection to a 0-based slot, clamped to `count` slots
+const fn to_slot(number: u8, count: u8) -> u8 {
+    match number {
+        0 => 0,
+        n if n > count => count - 1,
+        n => n - 1,
+    }
+}
+
+impl SbusPacket {
+    /// Decodes the VTX selection
+    ///
+    /// The range of channel [`VTX_BAND_CHANNEL_INDEX`] is divided into 40 equal
+    /// slots, band 1 channels 1 to 8 first, then band 2 and so on. The range of
+    /// channel [`VTX_POWER_CHANNEL_INDEX`] is divided into [`VTX_POWER_LEVELS`]
+    /// slots.
+    pub fn to_vtx_control(&self) -> BetaflightVtxControl {
+        let selection = slot(
+            self.channels[VTX_BAND_CHANNEL_INDEX],
+            VTX_BAND_COUNT * VTX_CHANNELS_PER_BAND,
+        );
+        BetaflightVtxControl {
+            band: selection / VTX_CHANNELS_PER_BAND + 1,
+            channel: selection % VTX_CHANNELS_PER_BAND + 1,
+            power: slot(self.channels[VTX_POWER_CHANNEL_INDEX], VTX_POWER_LEVELS) + 1,
+        }
+    }
+
+    /// Encodes a VTX selection, the inverse of [`SbusPacket::to_vtx_control`]
+    ///
+    /// Out-of-range values are clamped to the nearest valid one. The VTX channels
+    /// are set to the middle of their slot and the others to [`CHANNEL_MID`].
+    pub fn from_vtx_control(ctrl: BetaflightVtxControl) -> SbusPacket {
+        let selection = to_slot(ctrl.band, VTX_BAND_COUNT) * VTX_CHANNELS_PER_BAND
+            + to_slot(ctrl.channel, VTX_CHANNELS_PER_BAND);
+        let mut channels = [CHANNEL_MID; 16];
+        channels[VTX_BAND_CHANNEL_INDEX] =
+            slot_center(selection, VTX_BAND_COUNT * VTX_CHANNELS_PER_BAND);
+        channels[VTX_POWER_CHANNEL_INDEX] =
+            slot_center(to_slot(ctrl.power, VTX_POWER_LEVELS), VTX_POWER_LEVELS);
+        SbusPacket {
+            channels,
+            ..SbusPacket::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_vtx_channels(band_channel: u16, power: u16) -> SbusPacket {
+        let mut packet = SbusPacket::default();
+        packet.channels[VTX_BAND_CHANNEL_INDEX] = band_channel;
+        packet.channels[VTX_POWER_CHANNEL_INDEX] = power;
+        packet
+    }
+
+    #[test]
+    fn test_decode_range_ends() {
+        let low = with_vtx_channels(0, 0).to_vtx_control();
+        assert_eq!(
+            low,
+            BetaflightVtxControl {
+                band: 1,
+                channel: 1,
+                power: 1
+            }
+        );
+
+        let high = with_vtx_channels(CHANNEL_MAX, CHANNEL_MAX).to_vtx_control();
+        assert_eq!(
+            high,
+            BetaflightVtxControl {
+                band: VTX_BAND_COUNT,
+                channel: VTX_CHANNELS_PER_BAND,
+                power: VTX_POWER_LEVELS
+            }
+        );
+
+        // Slots are 51.2 counts wide: 51 is still band 1 channel 1, 52 is channel 2
+        assert_eq!(with_vtx_channels(51, 0).to_vtx_control().channel, 1);
+        assert_eq!(with_vtx_channels(52, 0).to_vtx_control().channel, 2);
+        assert_eq!(with_vtx_channels(410, 0).to_vtx_control().band, 2);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for band in 1..=VTX_BAND_COUNT {
+            for channel in 1..=VTX_CHANNELS_PER_BAND {
+                for power in 1..=VTX_POWER_LEVELS {
+                    let ctrl = BetaflightVtxControl {
+                        band,
+                        channel,
+                        power,
+                    };
+                    let packet = SbusPacket::from_vtx_control(ctrl);
+                    assert_eq!(packet.to_vtx_control(), ctrl);
+                    assert_eq!(packet.channels[0], CHANNEL_MID);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_clamps() {
+        let packet = SbusPacket::from_vtx_control(BetaflightVtxControl {
+            band: 9,
+            channel: 0,
+            power: 200,
+        });
+        assert_eq!(
+            packet.to_vtx_control(),
+            BetaflightVtxControl {
+                band: VTX_BAND_COUNT,
+                channel: 1,
+                power: VTX_POWER_LEVELS
+            }
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    pack_channel_data, packet::SbusPacket, unpack_channel_data, CHANNEL_DATA_LENGTH, CHANNEL_MID,
+};
+
+/// Length of a WifiBroadcast RC frame
+pub const WIFIBROADCAST_RC_LENGTH: usize = 11;
+/// Number of channels carried in a WifiBroadcast RC frame
+pub const WIFIBROADCAST_RC_CHANNELS: usize = 8;
+
+impl SbusPacket {
+    /// Packs channels 0-7 into a WifiBroadcast RC frame
+    ///
+    /// The 8 × 11 bits use the SBUS bit order, so the frame is the first 11 data
+    /// bytes of the SBUS frame. Channels 8-15 and the flags are dropped.
+    pub fn to_wifibroadcast_rc(&self) -> [u8; WIFIBROADCAST_RC_LENGTH] {
+        let mut channels = self.channels;
+        channels[WIFIBROADCAST_RC_CHANNELS..].fill(0);
+        let data = pack_channel_data(&channels);
+        let mut frame = [0; WIFIBROADCAST_RC_LENGTH];
+        frame.copy_from_slice(&data[..WIFIBROADCAST_RC_LENGTH]);
+        frame
+    }
+
+    /// Unpacks a WifiBroadcast RC frame, setting channels 8-15 to [`CHANNEL_MID`]
+    pub fn from_wifibroadcast_rc(data: &[u8; WIFIBROADCAST_RC_LENGTH]) -> SbusPacket {
+        let mut channel_data = [0; CHANNEL_DATA_LENGTH];
+        channel_data[..WIFIBROADCAST_RC_LENGTH].copy_from_slice(data);
+        let mut channels = unpack_channel_data(&channel_data);
+        channels[WIFIBROADCAST_RC_CHANNELS..].fill(CHANNEL_MID);
+        SbusPacket {
+            channels,
+            ..SbusPacket::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHANNEL_MAX;
+
+    #[test]
+    fn test_wifibroadcast_reference_packing() {
+        let packet = SbusPacket {
+            channels: [1024, 1, 0, 0, 0, 0, 0, CHANNEL_MAX, 5, 5, 5, 5, 5, 5, 5, 5],
+            ..SbusPacket::default()
+        };
+        // Channel 0 sets bit 10, channel 1 bit 11 and channel 7 bits 77-87
+        assert_eq!(
+            packet.to_wifibroadcast_rc(),
+            [0x00, 0x0C, 0, 0, 0, 0, 0, 0, 0, 0xE0, 0xFF]
+        );
+    }
+
+    #[test]
+    fn test_wifibroadcast_round_trip() {
+        let packet = SbusPacket {
+            channels: core::array::from_fn(|i| 172 + i as u16 * 101),
+            ..SbusPacket::default()
+        };
+        let decoded = SbusPacket::from_wifibroadcast_rc(&packet.to_wifibroadcast_rc());
+        assert_eq!(decoded.channels[..8], packet.channels[..8]);
+        assert_eq!(decoded.channels[8..], [CHANNEL_MID; 8]);
+        assert_eq!(decoded.to_wifibroadcast_rc(), packet.to_wifibroadcast_rc());
+    }
+}
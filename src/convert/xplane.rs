@@ -0,0 +1,50 @@
+use crate::{packet::SbusPacket, ticks_to_bipolar};
+
+/// Header of an X-Plane DATA packet
+pub const XPLANE_DATA_HEADER: [u8; 5] = *b"DATA\0";
+/// Data set index of the record carrying the channels
+pub const XPLANE_DATA_INDEX: i32 = 25;
+/// Length of a DATA packet with a single record: header, index and 8 values
+pub const XPLANE_DATA_PACKET_LENGTH: usize = 5 + 4 + 8 * 4;
+
+impl SbusPacket {
+    /// Encodes channels 0 to 7 as an X-Plane DATA packet
+    ///
+    /// The packet holds one record with index [`XPLANE_DATA_INDEX`] and the
+    /// channels mapped to `-1.0..=1.0`, all little-endian.
+    pub fn to_xplane_data_packet(&self) -> [u8; XPLANE_DATA_PACKET_LENGTH] {
+        let mut packet = [0u8; XPLANE_DATA_PACKET_LENGTH];
+        packet[..5].copy_from_slice(&XPLANE_DATA_HEADER);
+        packet[5..9].copy_from_slice(&XPLANE_DATA_INDEX.to_le_bytes());
+        for (chunk, &ticks) in packet[9..].chunks_exact_mut(4).zip(&self.channels) {
+            chunk.copy_from_slice(&ticks_to_bipolar(ticks).to_le_bytes());
+        }
+        packet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_xplane_data_packet() {
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[7] = CHANNEL_MAX;
+        packet.channels[8] = 0;
+
+        let data = packet.to_xplane_data_packet();
+        assert_eq!(data.len(), 41);
+        assert_eq!(data[..5], *b"DATA\0");
+        assert_eq!(data[5..9], [25, 0, 0, 0]);
+        // -1.0, 0.0 and 1.0 as IEEE 754 little-endian
+        assert_eq!(data[9..13], [0x00, 0x00, 0x80, 0xBF]);
+        assert_eq!(data[13..17], [0x00; 4]);
+        assert_eq!(data[37..41], [0x00, 0x00, 0x80, 0x3F]);
+    }
+}
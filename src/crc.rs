@@ -0,0 +1,75 @@
+//! Checksums used by the protocols in [`crate::convert`]
+
+/// CRC-8/DVB-S2 (polynomial 0xD5, init 0x00), used by CRSF
+#[cfg(feature = "crsf")]
+pub(crate) const fn crc8_dvb_s2(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    let mut i = 0;
+    while i < data.len() {
+        crc ^= data[i];
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0xD5
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+/// FrSky checksum used by S.Port and FPort: the byte sum with carries folded back
+/// in, subtracted from `0xFF`
+#[cfg(any(feature = "frsky", feature = "fport"))]
+pub(crate) fn frsky_checksum(bytes: &[u8]) -> u8 {
+    let crc = bytes.iter().fold(0u16, |crc, &byte| {
+        let crc = crc + byte as u16;
+        (crc + (crc >> 8)) & 0xFF
+    });
+    0xFF - crc as u8
+}
+
+/// CRC-16/XMODEM (CCITT polynomial 0x1021, init 0x0000), used by SRXL and SUMD
+#[cfg(any(feature = "hott", feature = "srxl"))]
+pub(crate) fn crc16_ccitt(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, &byte| {
+        (0..8).fold(crc ^ (byte as u16) << 8, |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "crsf")]
+    fn test_crc8_dvb_s2_check_value() {
+        assert_eq!(crc8_dvb_s2(b"123456789"), 0xBC);
+        assert_eq!(crc8_dvb_s2(&[]), 0x00);
+    }
+
+    #[test]
+    #[cfg(any(feature = "frsky", feature = "fport"))]
+    fn test_frsky_checksum() {
+        assert_eq!(frsky_checksum(&[]), 0xFF);
+        // 0xFF + 0x02 carries into 0x02
+        assert_eq!(frsky_checksum(&[0xFF, 0x02]), 0xFD);
+        assert_eq!(frsky_checksum(&[0x10, 0x00, 0x52]), 0xFF - 0x62);
+    }
+
+    #[test]
+    #[cfg(any(feature = "hott", feature = "srxl"))]
+    fn test_crc16_ccitt_check_value() {
+        assert_eq!(crc16_ccitt(b"123456789"), 0x31C3);
+        assert_eq!(crc16_ccitt(&[]), 0x0000);
+    }
+}
@@ -0,0 +1,81 @@
+//! Decoding to raw channel values and pulse widths in a single pass
+use crate::{
+    packet::{Flags, SbusPacket},
+    ranges::ChannelRange,
+    unpack_one, SbusError, CHANNEL_COUNT, SBUS_FRAME_LENGTH,
+};
+
+/// Frame decoded to both raw channel values and pulse widths
+///
+/// For firmware that logs raw values and drives servos from the same frame;
+/// the pulse widths are computed while unpacking instead of in a second pass.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DecodedFrame {
+    pub channels: [u16; CHANNEL_COUNT],
+    /// Pulse widths in µs following the [`ChannelRange`] passed to
+    /// [`DecodedFrame::from_array`]
+    pub channels_us: [u16; CHANNEL_COUNT],
+    pub flags: Flags,
+}
+
+impl DecodedFrame {
+    /// Validates and decodes a raw frame, converting channels with `range`
+    pub fn from_array(
+        buffer: &[u8; SBUS_FRAME_LENGTH],
+        range: &ChannelRange,
+    ) -> Result<Self, SbusError> {
+        SbusPacket::validate_frame(buffer)?;
+        let mut frame = DecodedFrame {
+            flags: Flags::from_byte(buffer[SBUS_FRAME_LENGTH - 2]),
+            ..DecodedFrame::default()
+        };
+        for idx in 0..CHANNEL_COUNT {
+            let value = unpack_one(&buffer[1..], idx);
+            frame.channels[idx] = value;
+            frame.channels_us[idx] = range.to_us(value);
+        }
+        Ok(frame)
+    }
+
+    /// Returns the raw packet, dropping the pulse widths
+    pub fn to_packet(&self) -> SbusPacket {
+        SbusPacket {
+            channels: self.channels,
+            flags: self.flags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ticks_to_us, CHANNEL_MAX};
+
+    #[test]
+    fn test_matches_separate_conversion() {
+        for step in [1, 7, 131] {
+            let packet = SbusPacket {
+                channels: core::array::from_fn(|i| (i as u16 * step * 17) % (CHANNEL_MAX + 1)),
+                flags: Flags::from_byte(0b1010),
+            };
+            let frame = packet.to_array();
+            for range in [ChannelRange::FULL, ChannelRange::STANDARD] {
+                let decoded = DecodedFrame::from_array(&frame, &range).unwrap();
+                assert_eq!(decoded.to_packet(), packet);
+                assert_eq!(decoded.channels_us, packet.channels.map(|v| range.to_us(v)));
+            }
+            let decoded = DecodedFrame::from_array(&frame, &ChannelRange::FULL).unwrap();
+            assert_eq!(decoded.channels_us, packet.channels.map(ticks_to_us));
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_frames() {
+        let mut frame = SbusPacket::default().to_array();
+        frame[24] = 0x04;
+        assert_eq!(
+            DecodedFrame::from_array(&frame, &ChannelRange::FULL),
+            Err(SbusError::InvalidFooter(0x04))
+        );
+    }
+}
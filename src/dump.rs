@@ -0,0 +1,261 @@
+//! Decode-and-print loop behind the `sbus_dump` example
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+use std::vec::Vec;
+
+use crate::{
+    channel_list::ChannelUnit, conversion::ticks_to_us, packet::SbusPacket, SBUS_FRAME_LENGTH,
+    SBUS_HEADER,
+};
+
+/// Running statistics of a decoded byte stream
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DumpStats {
+    /// Valid frames decoded
+    pub frames: u64,
+    /// Times the decoder lost alignment with the frame boundaries
+    pub sync_losses: u64,
+    /// Valid frames with the `frame_lost` flag set
+    pub lost_frames: u64,
+}
+
+impl DumpStats {
+    /// Percentage of decoded frames without the `frame_lost` flag
+    pub fn link_quality(&self) -> u8 {
+        if self.frames == 0 {
+            return 0;
+        }
+        ((self.frames - self.lost_frames) * 100 / self.frames) as u8
+    }
+}
+
+/// Decodes a raw byte stream and prints packets at a limited rate
+#[derive(Debug)]
+pub struct Dumper {
+    unit: ChannelUnit,
+    invert: bool,
+    interval: Duration,
+    buffer: Vec<u8>,
+    in_sync: bool,
+    last_print: Option<Instant>,
+    stats: DumpStats,
+}
+
+impl Dumper {
+    /// Creates a dumper printing channel values in `unit`, at most once per
+    /// `interval`
+    ///
+    /// With `invert`, every byte is inverted before decoding, for receivers whose
+    /// inverted output is connected without a hardware inverter.
+    pub fn new(unit: ChannelUnit, invert: bool, interval: Duration) -> Self {
+        Self {
+            unit,
+            invert,
+            interval,
+            buffer: Vec::with_capacity(2 * SBUS_FRAME_LENGTH),
+            in_sync: false,
+            last_print: None,
+            stats: DumpStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> &DumpStats {
+        &self.stats
+    }
+
+    /// Decodes `bytes` received at `now`, printing a line to `out` for the latest
+    /// packet if the interval since the last line has elapsed
+    pub fn feed<W: Write>(&mut self, bytes: &[u8], now: Instant, out: &mut W) -> io::Result<()> {
+        let invert = self.invert;
+        self.buffer
+            .extend(bytes.iter().map(|&b| if invert { !b } else { b }));
+
+        let mut latest = None;
+        let mut start = 0;
+        while self.buffer.len() - start >= SBUS_FRAME_LENGTH {
+            let frame: &[u8; SBUS_FRAME_LENGTH] = self.buffer[start..start + SBUS_FRAME_LENGTH]
+                .try_into()
+                .expect("slice has frame length");
+            match SbusPacket::from_array(frame) {
+                Ok(packet) => {
+                    self.in_sync = true;
+                    self.stats.frames += 1;
+                    self.stats.lost_frames += packet.flags.frame_lost as u64;
+                    latest = Some(packet);
+                    start += SBUS_FRAME_LENGTH;
+                }
+                Err(_) => {
+                    if self.in_sync {
+                        self.in_sync = false;
+                        self.stats.sync_losses += 1;
+                    }
+                    start += 1;
+                    // Skip straight to the next candidate header
+                    start += self.buffer[start..]
+                        .iter()
+                        .position(|&b| b == SBUS_HEADER)
+                        .unwrap_or(self.buffer.len() - start);
+                }
+            }
+        }
+        self.buffer.drain(..start);
+
+        let due = self
+            .last_print
+            .is_none_or(|last| now.duration_since(last) >= self.interval);
+        match latest {
+            Some(packet) if due => {
+                self.last_print = Some(now);
+                write_packet(out, &packet, self.unit, &self.stats)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Writes one line with the channel values, flags and statistics
+///
+/// For example `992 ... 992 | -- -- -- FS | frames 120 sync 1 LQ 100%`, where the
+/// flag field shows `D1`, `D2`, `FL` and `FS` when set.
+pub fn write_packet<W: Write>(
+    out: &mut W,
+    packet: &SbusPacket,
+    unit: ChannelUnit,
+    stats: &DumpStats,
+) -> io::Result<()> {
+    for value in packet.channels {
+        let value = match unit {
+            ChannelUnit::Ticks => value,
+            ChannelUnit::Microseconds => ticks_to_us(value),
+        };
+        write!(out, "{:4} ", value)?;
+    }
+    let flags = [
+        (packet.flags.d1, "D1"),
+        (packet.flags.d2, "D2"),
+        (packet.flags.frame_lost, "FL"),
+        (packet.flags.failsafe, "FS"),
+    ];
+    write!(out, "|")?;
+    for (set, label) in flags {
+        write!(out, " {}", if set { label } else { "--" })?;
+    }
+    writeln!(
+        out,
+        " | frames {} sync {} LQ {}%",
+        stats.frames,
+        stats.sync_losses,
+        stats.link_quality()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+
+    fn frame(flag_byte: u8) -> [u8; SBUS_FRAME_LENGTH] {
+        SbusPacket {
+            channels: [992; 16],
+            flags: Flags::from_byte(flag_byte),
+        }
+        .to_array()
+    }
+
+    #[test]
+    fn test_write_packet() {
+        let packet = SbusPacket {
+            channels: [1024; 16],
+            flags: Flags::from_byte(0b1001),
+        };
+        let stats = DumpStats {
+            frames: 4,
+            sync_losses: 1,
+            lost_frames: 1,
+        };
+
+        let mut out = Vec::new();
+        write_packet(&mut out, &packet, ChannelUnit::Microseconds, &stats).unwrap();
+        let line = String::from_utf8(out).unwrap();
+        assert_eq!(
+            line,
+            format!(
+                "{}| D1 -- -- FS | frames 4 sync 1 LQ 75%\n",
+                "1500 ".repeat(16)
+            )
+        );
+    }
+
+    #[test]
+    fn test_stats_and_resync() {
+        let mut dumper = Dumper::new(ChannelUnit::Ticks, false, Duration::ZERO);
+        let mut data = vec![0xAA, 0x55];
+        data.extend_from_slice(&frame(0));
+        data.extend_from_slice(&frame(0b0100));
+        data.extend_from_slice(&[0x01, 0x02, 0x03]);
+        data.extend_from_slice(&frame(0));
+
+        let mut out = Vec::new();
+        let now = Instant::now();
+        // Feed in odd-sized pieces to exercise frames split across calls
+        for chunk in data.chunks(7) {
+            dumper.feed(chunk, now, &mut out).unwrap();
+        }
+        assert_eq!(
+            *dumper.stats(),
+            DumpStats {
+                frames: 3,
+                sync_losses: 1,
+                lost_frames: 1,
+            }
+        );
+        assert_eq!(dumper.stats().link_quality(), 66);
+    }
+
+    #[test]
+    fn test_invert() {
+        let mut dumper = Dumper::new(ChannelUnit::Ticks, true, Duration::ZERO);
+        let inverted: Vec<u8> = frame(0).iter().map(|b| !b).collect();
+        dumper
+            .feed(&inverted, Instant::now(), &mut io::sink())
+            .unwrap();
+        assert_eq!(dumper.stats().frames, 1);
+    }
+
+    #[test]
+    fn test_print_rate() {
+        let mut dumper = Dumper::new(ChannelUnit::Ticks, false, Duration::from_millis(100));
+        let start = Instant::now();
+        let mut out = Vec::new();
+        for i in 0..30 {
+            let now = start + Duration::from_millis(i * 14);
+            dumper.feed(&frame(0), now, &mut out).unwrap();
+        }
+        // Frames span 406 ms, printed at 0, 112, 224 and 336 ms
+        let lines = String::from_utf8(out).unwrap().lines().count();
+        assert_eq!(lines, 4);
+        assert_eq!(dumper.stats().frames, 30);
+    }
+
+    #[test]
+    fn test_header_noise() {
+        let mut dumper = Dumper::new(ChannelUnit::Ticks, false, Duration::ZERO);
+        let now = Instant::now();
+        let noise = [SBUS_HEADER; 10 * SBUS_FRAME_LENGTH];
+        dumper.feed(&noise, now, &mut io::sink()).unwrap();
+        // Only the tail that may still start a frame is kept
+        assert!(dumper.buffer.len() < SBUS_FRAME_LENGTH);
+
+        // A header byte right before a frame is not mistaken for its start
+        let mut data = vec![SBUS_HEADER];
+        data.extend_from_slice(&frame(0b0001));
+        data.extend_from_slice(&noise);
+        data.extend_from_slice(&frame(0b0001));
+        for chunk in data.chunks(3) {
+            dumper.feed(chunk, now, &mut io::sink()).unwrap();
+        }
+        assert_eq!(dumper.stats().frames, 2);
+        assert_eq!(dumper.stats().sync_losses, 1);
+        assert!(dumper.buffer.len() < SBUS_FRAME_LENGTH);
+    }
+}
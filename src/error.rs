@@ -1,3 +1,4 @@
+//! Error types
 /// Error types for SBUS operations
 #[derive(Debug, PartialEq, Eq)]
 pub enum SbusError {
@@ -7,4 +8,94 @@ pub enum SbusError {
     InvalidHeader(u8),
     /// Invalid footer
     InvalidFooter(u8),
+    /// Malformed payload in a non-SBUS format
+    InvalidFormat,
+    /// Parity bit mismatch in the byte at the given frame index
+    ParityError(usize),
+    /// Wrong start or stop bit in the byte at the given frame index
+    FramingError(usize),
+}
+
+/// Error types for channel identifiers
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChannelIdError {
+    /// Channel index outside of `0..CHANNEL_COUNT`
+    OutOfRange(usize),
+    /// Name is neither `ch1`..`ch16` nor a known alias
+    UnknownName,
+}
+
+/// Error returned when a pulse width in µs is outside `US_MIN..=US_MAX`, or the
+/// pulse width range of a [`crate::ChannelRange`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct PulseWidthOutOfRange(pub u16);
+
+/// Error returned when a human-entered channel list cannot be parsed
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseChannelsError {
+    /// Byte offset of the offending token in the input
+    pub position: usize,
+    pub kind: ParseChannelsErrorKind,
+}
+
+/// Reason a channel list was rejected
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseChannelsErrorKind {
+    /// The token is empty (e.g. a trailing comma)
+    EmptyToken,
+    /// The value is not a decimal integer
+    InvalidValue,
+    /// The repeat count after `x` is not a positive decimal integer
+    InvalidCount,
+    /// The value is outside the range allowed by the unit
+    OutOfRange(u16),
+    /// The list describes more than `CHANNEL_COUNT` channels
+    TooManyChannels,
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for ParseChannelsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ParseChannelsErrorKind::EmptyToken => write!(f, "empty value")?,
+            ParseChannelsErrorKind::InvalidValue => write!(f, "invalid channel value")?,
+            ParseChannelsErrorKind::InvalidCount => write!(f, "invalid repeat count")?,
+            ParseChannelsErrorKind::OutOfRange(value) => {
+                write!(f, "channel value {} out of range", value)?
+            }
+            ParseChannelsErrorKind::TooManyChannels => write!(f, "too many channels")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseChannelsError {}
+
+/// Error returned when the SBUS self-test fails to recover a test vector
+#[derive(Debug, PartialEq, Eq)]
+pub struct SelfTestFailure {
+    /// Index of the failing test vector
+    pub vector: usize,
+    pub kind: SelfTestFailureKind,
+}
+
+/// Reason a self-test vector failed
+#[derive(Debug, PartialEq, Eq)]
+pub enum SelfTestFailureKind {
+    /// The encoded frame could not be decoded
+    Decode(SbusError),
+    /// The frame decoded to a different packet
+    Mismatch,
+}
+
+/// Reason a PWM timer configuration was rejected
+#[derive(Debug, PartialEq, Eq)]
+pub enum PwmConfigError {
+    /// The tick or PWM frequency is zero
+    ZeroFrequency,
+    /// The timer period, in ticks, is shorter than the longest standard pulse
+    PeriodTooShort { period: u32, max_compare: u32 },
 }
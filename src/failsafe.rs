@@ -0,0 +1,225 @@
+//! Failsafe handling helpers
+use crate::{packet::SbusPacket, CHANNEL_COUNT, CHANNEL_MID};
+
+/// Channel substitution strategy used by Cleanflight/Betaflight's RX failsafe
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RxFailMode {
+    /// Replace every channel with [`CHANNEL_MID`]
+    #[default]
+    Auto,
+    /// Keep the channel values of the last good packet
+    Hold,
+    /// Replace every channel with the configured [`RxFailConfig::values`]
+    Set,
+}
+
+/// RX failsafe configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RxFailConfig {
+    pub mode: RxFailMode,
+    /// Per-channel values used by [`RxFailMode::Set`]
+    pub values: [u16; CHANNEL_COUNT],
+}
+
+impl Default for RxFailConfig {
+    fn default() -> Self {
+        Self {
+            mode: RxFailMode::Auto,
+            values: [CHANNEL_MID; CHANNEL_COUNT],
+        }
+    }
+}
+
+/// Substitution applied to packets flagged as failsafe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailsafeMode {
+    /// Use the last good packet
+    Hold,
+    /// Center every channel at [`CHANNEL_MID`]
+    Center,
+    /// Use a fixed packet
+    Custom(SbusPacket),
+}
+
+impl SbusPacket {
+    /// Returns `true` if the receiver reports a failsafe or a lost frame
+    pub fn is_rxfail_active(&self) -> bool {
+        self.flags.failsafe || self.flags.frame_lost
+    }
+
+    /// Returns `true` if the receiver reports a failsafe
+    pub fn is_failsafe(&self) -> bool {
+        self.flags.failsafe
+    }
+
+    /// Substitutes safe values for a packet in failsafe
+    ///
+    /// Packets not in failsafe are returned unchanged. Otherwise `Hold` returns
+    /// `saved_last_good` and `Custom` the stored packet, both as they are, while
+    /// `Center` keeps this packet's flags.
+    pub fn apply_failsafe_mode(
+        &self,
+        mode: FailsafeMode,
+        saved_last_good: &SbusPacket,
+    ) -> SbusPacket {
+        if !self.is_failsafe() {
+            return *self;
+        }
+
+        match mode {
+            FailsafeMode::Hold => *saved_last_good,
+            FailsafeMode::Center => SbusPacket {
+                channels: [CHANNEL_MID; CHANNEL_COUNT],
+                flags: self.flags,
+            },
+            FailsafeMode::Custom(packet) => packet,
+        }
+    }
+
+    /// Applies the RX failsafe configuration
+    ///
+    /// Packets without an active RX failure are returned unchanged. `Hold` takes the
+    /// channels of `saved_last_good`; flags are always preserved so downstream code
+    /// can still see the failure.
+    pub fn apply_rxfail_config(
+        &self,
+        config: &RxFailConfig,
+        saved_last_good: &SbusPacket,
+    ) -> SbusPacket {
+        if !self.is_rxfail_active() {
+            return *self;
+        }
+
+        let channels = match config.mode {
+            RxFailMode::Auto => [CHANNEL_MID; CHANNEL_COUNT],
+            RxFailMode::Hold => saved_last_good.channels,
+            RxFailMode::Set => config.values,
+        };
+
+        SbusPacket {
+            channels,
+            flags: self.flags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+
+    fn packet(flag_byte: u8) -> SbusPacket {
+        SbusPacket {
+            channels: [1500; CHANNEL_COUNT],
+            flags: Flags::from_byte(flag_byte),
+        }
+    }
+
+    fn saved() -> SbusPacket {
+        SbusPacket {
+            channels: [1200; CHANNEL_COUNT],
+            flags: Flags::from_byte(0),
+        }
+    }
+
+    fn config(mode: RxFailMode) -> RxFailConfig {
+        let mut values = [0u16; CHANNEL_COUNT];
+        values
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = 100 * i as u16);
+        RxFailConfig { mode, values }
+    }
+
+    #[test]
+    fn test_rxfail_active() {
+        assert!(!packet(0b0000).is_rxfail_active());
+        assert!(!packet(0b0011).is_rxfail_active());
+        assert!(packet(0b0100).is_rxfail_active());
+        assert!(packet(0b1000).is_rxfail_active());
+        assert!(packet(0b1100).is_rxfail_active());
+    }
+
+    #[test]
+    fn test_healthy_packet_untouched() {
+        let healthy = packet(0);
+        for mode in [RxFailMode::Auto, RxFailMode::Hold, RxFailMode::Set] {
+            assert_eq!(
+                healthy.apply_rxfail_config(&config(mode), &saved()),
+                healthy
+            );
+        }
+    }
+
+    #[test]
+    fn test_auto_mode() {
+        let result = packet(0b1000).apply_rxfail_config(&config(RxFailMode::Auto), &saved());
+        assert_eq!(result.channels, [CHANNEL_MID; CHANNEL_COUNT]);
+        assert!(result.flags.failsafe);
+    }
+
+    #[test]
+    fn test_hold_mode() {
+        let failed = packet(0b0100);
+        let saved = saved();
+        assert_ne!(failed.channels, saved.channels);
+        let result = failed.apply_rxfail_config(&config(RxFailMode::Hold), &saved);
+        assert_eq!(result.channels, saved.channels);
+        assert_eq!(result.flags, failed.flags);
+    }
+
+    #[test]
+    fn test_set_mode() {
+        let config = config(RxFailMode::Set);
+        let result = packet(0b1100).apply_rxfail_config(&config, &saved());
+        assert_eq!(result.channels, config.values);
+        assert_eq!(result.flags, Flags::from_byte(0b1100));
+    }
+
+    #[test]
+    fn test_failsafe_modes() {
+        let saved = packet(0);
+        let custom = SbusPacket {
+            channels: [172; CHANNEL_COUNT],
+            flags: Flags::from_byte(0),
+        };
+        let modes = [
+            FailsafeMode::Hold,
+            FailsafeMode::Center,
+            FailsafeMode::Custom(custom),
+        ];
+
+        // Not in failsafe, including a lost frame without the failsafe flag
+        for flag_byte in [0b0000, 0b0100] {
+            let input = SbusPacket {
+                channels: [900; CHANNEL_COUNT],
+                flags: Flags::from_byte(flag_byte),
+            };
+            assert!(!input.is_failsafe());
+            for mode in modes {
+                assert_eq!(input.apply_failsafe_mode(mode, &saved), input);
+            }
+        }
+
+        let failed = SbusPacket {
+            channels: [900; CHANNEL_COUNT],
+            flags: Flags::from_byte(0b1000),
+        };
+        assert!(failed.is_failsafe());
+        assert_eq!(
+            failed.apply_failsafe_mode(FailsafeMode::Hold, &saved),
+            saved
+        );
+        assert_eq!(
+            failed.apply_failsafe_mode(FailsafeMode::Center, &saved),
+            SbusPacket {
+                channels: [CHANNEL_MID; CHANNEL_COUNT],
+                flags: failed.flags,
+            }
+        );
+        assert_eq!(
+            failed.apply_failsafe_mode(FailsafeMode::Custom(custom), &saved),
+            custom
+        );
+    }
+}
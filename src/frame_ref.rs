@@ -0,0 +1,117 @@
+//! Borrowed view of a raw frame with lazy channel extraction
+use crate::{
+    packet::{Flags, SbusPacket},
+    unpack_one, SbusError, CHANNEL_COUNT, SBUS_FRAME_LENGTH,
+};
+
+/// Validated reference to a raw SBUS frame
+///
+/// Channels and flags are extracted on demand, so inspecting a few channels costs
+/// less than decoding a full [`SbusPacket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SbusFrameRef<'a> {
+    frame: &'a [u8; SBUS_FRAME_LENGTH],
+}
+
+impl<'a> SbusFrameRef<'a> {
+    /// Wraps a frame after validating its header and footer
+    pub fn new(frame: &'a [u8; SBUS_FRAME_LENGTH]) -> Result<Self, SbusError> {
+        SbusPacket::validate_frame(frame)?;
+        Ok(Self { frame })
+    }
+
+    /// Extracts the value of channel `idx`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not below [`CHANNEL_COUNT`].
+    pub fn channel(&self, idx: usize) -> u16 {
+        assert!(idx < CHANNEL_COUNT, "channel index {} out of range", idx);
+        unpack_one(&self.frame[1..], idx)
+    }
+
+    pub fn flags(&self) -> Flags {
+        Flags::from_byte(self.frame[SBUS_FRAME_LENGTH - 2])
+    }
+
+    /// Decodes the full packet
+    pub fn to_packet(&self) -> SbusPacket {
+        SbusPacket::from_validated_array(self.frame)
+    }
+
+    /// Returns the underlying frame
+    pub fn as_bytes(&self) -> &'a [u8; SBUS_FRAME_LENGTH] {
+        self.frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{unpack_channels, CHANNEL_MAX};
+
+    #[test]
+    fn test_rejects_invalid_frames() {
+        let mut frame = SbusPacket::default().to_array();
+        frame[0] = 0x0E;
+        assert_eq!(
+            SbusFrameRef::new(&frame),
+            Err(SbusError::InvalidHeader(0x0E))
+        );
+        frame[0] = 0x0F;
+        frame[24] = 0x04;
+        assert_eq!(
+            SbusFrameRef::new(&frame),
+            Err(SbusError::InvalidFooter(0x04))
+        );
+    }
+
+    #[test]
+    fn test_channel_matches_bulk_unpack() {
+        // Every value on every channel, with neighbours at both extremes
+        for neighbours in [0, CHANNEL_MAX] {
+            for idx in 0..CHANNEL_COUNT {
+                for value in 0..=CHANNEL_MAX {
+                    let mut packet = SbusPacket {
+                        channels: [neighbours; CHANNEL_COUNT],
+                        flags: Flags::from_byte(0x0F),
+                    };
+                    packet.channels[idx] = value;
+                    let frame = packet.to_array();
+                    let view = SbusFrameRef::new(&frame).unwrap();
+
+                    let bulk = unpack_channels(&frame);
+                    for (i, &expected) in bulk.iter().enumerate() {
+                        assert_eq!(view.channel(i), expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_flags_and_packet() {
+        let mut packet = SbusPacket {
+            flags: Flags::from_byte(0b0110),
+            ..SbusPacket::default()
+        };
+        packet
+            .channels
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = i as u16 * 131);
+        let frame = packet.to_array();
+        let view = SbusFrameRef::new(&frame).unwrap();
+
+        assert_eq!(view.flags(), packet.flags);
+        assert_eq!(view.to_packet(), packet);
+        assert_eq!(view.as_bytes(), &frame);
+    }
+
+    #[test]
+    #[should_panic(expected = "channel index 16 out of range")]
+    fn test_channel_out_of_range() {
+        let frame = SbusPacket::default().to_array();
+        SbusFrameRef::new(&frame).unwrap().channel(16);
+    }
+}
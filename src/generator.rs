@@ -0,0 +1,190 @@
+//! Scripted frame generation and pacing behind the `sbus_gen` example
+use std::time::{Duration, Instant};
+use std::vec::Vec;
+
+use crate::{
+    packet::{Flags, SbusPacket},
+    CHANNEL_MAX, SBUS_FRAME_LENGTH,
+};
+
+/// Frame period of SBUS in high-speed mode
+pub const SBUS_FRAME_PERIOD_FAST: Duration = Duration::from_millis(7);
+/// Frame period of SBUS in normal mode
+pub const SBUS_FRAME_PERIOD_NORMAL: Duration = Duration::from_millis(14);
+
+/// Source of the generated packets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pattern {
+    /// The same packet in every frame
+    Fixed(SbusPacket),
+    /// All channels ramp from 0 to [`CHANNEL_MAX`] and back over `period` frames
+    Sweep { period: u32 },
+    /// Packets of a capture, repeated from the start once exhausted
+    Replay(Vec<SbusPacket>),
+}
+
+/// Endless iterator of encoded frames following a [`Pattern`]
+///
+/// The flags passed to [`FrameGenerator::new`] are set on top of the flags of the
+/// pattern's packets.
+#[derive(Debug, Clone)]
+pub struct FrameGenerator {
+    pattern: Pattern,
+    flags: Flags,
+    index: u64,
+}
+
+impl FrameGenerator {
+    pub fn new(pattern: Pattern, flags: Flags) -> Self {
+        Self {
+            pattern,
+            flags,
+            index: 0,
+        }
+    }
+
+    /// Returns the next packet, or `None` if a replay has no packets
+    pub fn next_packet(&mut self) -> Option<SbusPacket> {
+        let packet = match &self.pattern {
+            Pattern::Fixed(packet) => *packet,
+            Pattern::Sweep { period } => SbusPacket {
+                channels: [sweep_value(self.index, *period); 16],
+                flags: Flags::default(),
+            },
+            Pattern::Replay(packets) if packets.is_empty() => return None,
+            Pattern::Replay(packets) => packets[(self.index % packets.len() as u64) as usize],
+        };
+        self.index += 1;
+
+        let flags = Flags::from_byte(packet.flags.to_byte() | self.flags.to_byte());
+        Some(SbusPacket { flags, ..packet })
+    }
+}
+
+impl Iterator for FrameGenerator {
+    type Item = [u8; SBUS_FRAME_LENGTH];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_packet().map(|packet| packet.to_array())
+    }
+}
+
+/// Triangle wave over `period` frames, 0 at the start and [`CHANNEL_MAX`] halfway
+fn sweep_value(index: u64, period: u32) -> u16 {
+    let period = period.max(2) as u64;
+    let half = period / 2;
+    let position = index % period;
+    let distance = if position <= half {
+        position
+    } else {
+        period - position
+    };
+    ((distance * CHANNEL_MAX as u64 + half / 2) / half).min(CHANNEL_MAX as u64) as u16
+}
+
+/// Computes transmit deadlines at a fixed frame period without accumulating drift
+#[derive(Debug, Clone)]
+pub struct FramePacer {
+    period: Duration,
+    next: Instant,
+}
+
+impl FramePacer {
+    /// Creates a pacer whose first deadline is `start`
+    pub fn new(period: Duration, start: Instant) -> Self {
+        Self {
+            period,
+            next: start,
+        }
+    }
+
+    /// Returns the deadline of the next frame given the current time
+    ///
+    /// Deadlines advance by exactly one period. If the caller fell behind by more
+    /// than a period, the schedule restarts at `now` instead of sending a burst of
+    /// late frames.
+    pub fn next_deadline(&mut self, now: Instant) -> Instant {
+        if now > self.next + self.period {
+            self.next = now;
+        }
+        let deadline = self.next;
+        self.next += self.period;
+        deadline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHANNEL_MID;
+
+    #[test]
+    fn test_fixed_pattern_with_flags() {
+        let packet = SbusPacket {
+            channels: [CHANNEL_MID; 16],
+            flags: Flags::from_byte(0b0001),
+        };
+        let failsafe = Flags {
+            failsafe: true,
+            ..Flags::default()
+        };
+        let mut generator = FrameGenerator::new(Pattern::Fixed(packet), failsafe);
+        for frame in generator.by_ref().take(3) {
+            let decoded = SbusPacket::from_array(&frame).unwrap();
+            assert_eq!(decoded.channels, packet.channels);
+            assert_eq!(decoded.flags.to_byte(), 0b1001);
+        }
+    }
+
+    #[test]
+    fn test_sweep_pattern() {
+        let mut generator = FrameGenerator::new(Pattern::Sweep { period: 8 }, Flags::default());
+        let values: Vec<u16> = (0..9)
+            .map(|_| generator.next_packet().unwrap().channels[0])
+            .collect();
+        assert_eq!(values, [0, 512, 1024, 1535, 2047, 1535, 1024, 512, 0]);
+    }
+
+    #[test]
+    fn test_replay_pattern_loops() {
+        let packets: Vec<SbusPacket> = (0..3)
+            .map(|i| SbusPacket {
+                channels: [i * 100; 16],
+                ..SbusPacket::default()
+            })
+            .collect();
+        let mut generator = FrameGenerator::new(Pattern::Replay(packets), Flags::default());
+        let values: Vec<u16> = (0..7)
+            .map(|_| generator.next_packet().unwrap().channels[0])
+            .collect();
+        assert_eq!(values, [0, 100, 200, 0, 100, 200, 0]);
+
+        let mut empty = FrameGenerator::new(Pattern::Replay(Vec::new()), Flags::default());
+        assert_eq!(empty.next(), None);
+    }
+
+    #[test]
+    fn test_pacer_fixed_period() {
+        let start = Instant::now();
+        let mut pacer = FramePacer::new(SBUS_FRAME_PERIOD_NORMAL, start);
+        // Waking up a little late must not delay the following deadlines
+        for i in 0..10 {
+            let now = start + SBUS_FRAME_PERIOD_NORMAL * i + Duration::from_millis(1);
+            assert_eq!(
+                pacer.next_deadline(now),
+                start + SBUS_FRAME_PERIOD_NORMAL * i
+            );
+        }
+    }
+
+    #[test]
+    fn test_pacer_restarts_after_stall() {
+        let start = Instant::now();
+        let mut pacer = FramePacer::new(SBUS_FRAME_PERIOD_FAST, start);
+        assert_eq!(pacer.next_deadline(start), start);
+
+        let late = start + Duration::from_millis(100);
+        assert_eq!(pacer.next_deadline(late), late);
+        assert_eq!(pacer.next_deadline(late), late + SBUS_FRAME_PERIOD_FAST);
+    }
+}
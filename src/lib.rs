@@ -9,11 +9,40 @@
 //! - `blocking`: Enables blocking I/O operations (enabled by default)
 //! - `async`: Enables async I/O operations
 //! - `std`: Enables standard library features
+//! - `defmt`: Implements `defmt::Format` for log-friendly types
+//! - `json`: Enables JSON conversions (DroneKit RC overrides)
+//! - `crsf`: Enables TBS Crossfire (CRSF) frame encoding
+//! - `elrs`: Enables EdgeTX SBUS-over-ELRS frame decoding
+//! - `blackbox`: Enables Betaflight blackbox RC record encoding
+//! - `uavcan`: Enables UAVCAN/DroneCAN RC input normalization
+//! - `openpilot`: Enables OpenPilot/LibrePilot RC input conversion
+//! - `sitl`: Enables ArduPilot SITL RC input frames
+//! - `flightgear`: Enables FlightGear generic protocol control input
+//! - `frsky`: Enables FrSky S.Port custom sensor encoding
+//! - `xplane`: Enables X-Plane DATA packet encoding
+//! - `jsbsim`: Enables JSBSim socket control properties
+//! - `betaflight`: Enables Betaflight VTX band, channel and power control
+//! - `opentx`: Enables OpenTX/EdgeTX Lua telemetry payloads
+//! - `proptest`: Exposes proptest strategies for packets, frames and noisy streams
+//! - `dronecan`: Enables DroneCAN ESC RawCommand encoding
+//! - `fport`: Enables FrSky FPort2 control frame encoding
+//! - `srxl`: Enables Multiplex SRXL v1 frame encoding
+//! - `test-util`: Exposes end-to-end test harnesses for encoders and decoders
+//! - `msp`: Enables MultiWii serial protocol RC frames
+//! - `hott`: Enables Graupner HoTT SUMD frame conversion
+//! - `hexflight`: Enables HexFlight simulator UDP datagrams
+//! - `qgc`: Enables QGroundControl joystick axes and MANUAL_CONTROL JSON
+//! - `opensbus`: Enables OpenSBUS 32-channel packets over pairs of frames
+//! - `wifibroadcast`: Enables WifiBroadcast 8-channel RC frames
+//! - `cyphal`: Enables Cyphal (UAVCAN v1) RC input serialization
+//! - `opencockpits`: Enables OpenCockpits IOCards HID axis reports
+//! - `soft-uart`: Enables frame conversion to and from software UART bits, and with
+//!   `std` the Sigrok logic-level export
 //!
 //! ## Example
 //!
 //! ```rust
-//! use sbus_rs::{SbusParser, SbusPacket};
+//! use sbus_rs::prelude::*;
 //! use embedded_io_adapters::std::FromStd;
 //! use std::io::Cursor;
 //!
@@ -32,6 +61,9 @@
 //! }
 //! ```
 //!
+//! Everything needed for the common case is re-exported from [`prelude`]; every item
+//! is also available from the crate root and from its defining module.
+//!
 //! ## Protocol Details
 //!
 //! SBUS frames are 25 bytes long with the following structure:
@@ -40,119 +72,299 @@
 //! - 1 byte of flags
 //! - End byte (0x00)
 
+pub use arm::*;
+#[cfg(target_has_atomic = "ptr")]
+pub use bridge::*;
+pub use build_info::*;
+#[cfg(feature = "std")]
+pub use capture::*;
+pub use channel::*;
+#[cfg(feature = "std")]
+pub use channel_list::*;
+pub use compact::*;
+pub use continuity::*;
+pub use conversion::*;
+pub use convert::*;
+pub use decoded::*;
+#[cfg(feature = "std")]
+pub use dump::*;
 pub use error::*;
+pub use failsafe::*;
+pub use frame_ref::*;
+#[cfg(feature = "std")]
+pub use generator::*;
+#[cfg(feature = "test-util")]
+pub use loopback::*;
+#[cfg(feature = "opensbus")]
+pub use opensbus::*;
+pub use packed::*;
 pub use packet::*;
 pub use parser::*;
+pub use pwm::*;
+pub use ranges::*;
+#[cfg(feature = "std")]
+pub use regression::*;
+pub use scale::*;
+pub use selftest::*;
+#[cfg(feature = "soft-uart")]
+pub use soft_uart::*;
+#[cfg(feature = "proptest")]
+pub use strategies::*;
+#[cfg(feature = "test-util")]
+pub use sweep::*;
+pub use sync::*;
+pub use units::*;
+pub use watcher::*;
+
+pub mod arm;
+#[cfg(target_has_atomic = "ptr")]
+pub mod bridge;
+pub mod build_info;
+#[cfg(feature = "std")]
+pub mod capture;
+pub mod channel;
+#[cfg(feature = "std")]
+pub mod channel_list;
+pub mod compact;
+pub mod continuity;
+pub mod conversion;
+pub mod convert;
+#[cfg(any(
+    feature = "crsf",
+    feature = "frsky",
+    feature = "fport",
+    feature = "hott",
+    feature = "srxl"
+))]
+mod crc;
+pub mod decoded;
+#[cfg(feature = "std")]
+pub mod dump;
+pub mod error;
+pub mod failsafe;
+pub mod frame_ref;
+#[cfg(feature = "std")]
+pub mod generator;
+#[cfg(feature = "test-util")]
+pub mod loopback;
+mod macros;
+#[cfg(feature = "opensbus")]
+pub mod opensbus;
+pub mod packed;
+pub mod packet;
+pub mod parser;
+pub mod prelude;
+pub mod pwm;
+pub mod ranges;
+#[cfg(feature = "std")]
+pub mod regression;
+pub mod scale;
+pub mod selftest;
+#[cfg(feature = "soft-uart")]
+pub mod soft_uart;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(feature = "test-util")]
+pub mod sweep;
+pub mod sync;
+pub mod units;
+pub mod watcher;
+
+// Guards the channel packing at compile time
+const _: () = {
+    const CHANNELS: [u16; CHANNEL_COUNT] = [
+        0,
+        1,
+        2,
+        4,
+        8,
+        16,
+        32,
+        64,
+        128,
+        256,
+        512,
+        1024,
+        172,
+        992,
+        1811,
+        CHANNEL_MAX,
+    ];
+    match SbusPacket::from_array(&sbus_frame!(CHANNELS, 0b1010)) {
+        Ok(packet) => {
+            let mut i = 0;
+            while i < CHANNEL_COUNT {
+                assert!(packet.channels[i] == CHANNELS[i]);
+                i += 1;
+            }
+            assert!(packet.flags.to_byte() == 0b1010);
+        }
+        Err(_) => panic!("sbus_frame! produced an invalid frame"),
+    }
+};
 
-mod error;
-mod packet;
-mod parser;
+/// Unpacks the 16 11-bit channel values from a raw SBUS frame
+///
+/// Only the channel data bytes are read; header, flags and footer are neither
+/// decoded nor validated.
+#[inline(always)]
+pub const fn unpack_channels(buffer: &[u8; SBUS_FRAME_LENGTH]) -> [u16; CHANNEL_COUNT] {
+    let (_, data) = buffer.split_at(1);
+    unpack(data)
+}
 
+/// Unpacks the 16 11-bit channel values from the channel data bytes of a frame
+///
+/// `data` holds the 22 bytes following the header, for frame layouts that carry
+/// SBUS channel data elsewhere.
+#[inline(always)]
+pub const fn unpack_channel_data(data: &[u8; CHANNEL_DATA_LENGTH]) -> [u16; CHANNEL_COUNT] {
+    unpack(data)
+}
+
+/// Same as [`unpack_channels`]
 #[inline(always)]
 pub const fn channels_parsing(buffer: &[u8; SBUS_FRAME_LENGTH]) -> [u16; CHANNEL_COUNT] {
-    [
-        ((buffer[1] as u16) | ((buffer[2] as u16) << 8)) & CHANNEL_MAX,
-        (((buffer[2] as u16) >> 3) | ((buffer[3] as u16) << 5)) & CHANNEL_MAX,
-        (((buffer[3] as u16) >> 6) | ((buffer[4] as u16) << 2) | ((buffer[5] as u16) << 10))
-            & CHANNEL_MAX,
-        (((buffer[5] as u16) >> 1) | ((buffer[6] as u16) << 7)) & CHANNEL_MAX,
-        (((buffer[6] as u16) >> 4) | ((buffer[7] as u16) << 4)) & CHANNEL_MAX,
-        (((buffer[7] as u16) >> 7) | ((buffer[8] as u16) << 1) | ((buffer[9] as u16) << 9))
-            & CHANNEL_MAX,
-        (((buffer[9] as u16) >> 2) | ((buffer[10] as u16) << 6)) & CHANNEL_MAX,
-        (((buffer[10] as u16) >> 5) | ((buffer[11] as u16) << 3)) & CHANNEL_MAX,
-        ((buffer[12] as u16) | ((buffer[13] as u16) << 8)) & CHANNEL_MAX,
-        (((buffer[13] as u16) >> 3) | ((buffer[14] as u16) << 5)) & CHANNEL_MAX,
-        (((buffer[14] as u16) >> 6) | ((buffer[15] as u16) << 2) | ((buffer[16] as u16) << 10))
-            & CHANNEL_MAX,
-        (((buffer[16] as u16) >> 1) | ((buffer[17] as u16) << 7)) & CHANNEL_MAX,
-        (((buffer[17] as u16) >> 4) | ((buffer[18] as u16) << 4)) & CHANNEL_MAX,
-        (((buffer[18] as u16) >> 7) | ((buffer[19] as u16) << 1) | ((buffer[20] as u16) << 9))
-            & CHANNEL_MAX,
-        (((buffer[20] as u16) >> 2) | ((buffer[21] as u16) << 6)) & CHANNEL_MAX,
-        (((buffer[21] as u16) >> 5) | ((buffer[22] as u16) << 3)) & CHANNEL_MAX,
-    ]
+    unpack_channels(buffer)
 }
 
+/// Loads up to 8 bytes of `data` starting at `start` as a little-endian word
 #[inline(always)]
-pub fn pack_channels(buffer: &mut [u8; SBUS_FRAME_LENGTH], channels: &[u16; CHANNEL_COUNT]) {
-    // Clear the buffer first (except header and footer)
-    let mut i = 1;
-    while i < SBUS_FRAME_LENGTH - 1 {
-        buffer[i] = 0;
+const fn load_le(data: &[u8], start: usize, len: usize) -> u64 {
+    let mut word = 0;
+    let mut i = 0;
+    while i < len {
+        word |= (data[start + i] as u64) << (8 * i);
         i += 1;
     }
+    word
+}
+
+/// Unpacks the 16 channels of the 22 channel data bytes at the start of `data`
+///
+/// The data is read as three little-endian words holding bits 0-63, 64-127 and
+/// 128-175; each channel is shifted and masked out of its word, combined with the
+/// next word for the two channels straddling a word boundary.
+#[inline(always)]
+const fn unpack(data: &[u8]) -> [u16; CHANNEL_COUNT] {
+    let words = [
+        load_le(data, 0, 8),
+        load_le(data, 8, 8),
+        load_le(data, 16, CHANNEL_DATA_LENGTH - 16),
+    ];
+    let mut channels = [0u16; CHANNEL_COUNT];
+    let mut idx = 0;
+    while idx < CHANNEL_COUNT {
+        let bit = idx * 11;
+        let (word, shift) = (bit / 64, bit % 64);
+        let mut bits = words[word] >> shift;
+        if shift > 64 - 11 {
+            bits |= words[word + 1] << (64 - shift);
+        }
+        channels[idx] = bits as u16 & CHANNEL_MAX;
+        idx += 1;
+    }
+    channels
+}
+
+/// Extracts channel `idx` from channel data followed by at least one more byte
+#[inline(always)]
+pub(crate) const fn unpack_one(data: &[u8], idx: usize) -> u16 {
+    let bit = idx * 11;
+    let byte = bit / 8;
+    // The 11 bits span at most three bytes, the last one possibly past the data
+    let bits = data[byte] as u32 | (data[byte + 1] as u32) << 8 | (data[byte + 2] as u32) << 16;
+    (bits >> (bit % 8)) as u16 & CHANNEL_MAX
+}
+
+/// Packs 16 channel values into the data bytes of a raw SBUS frame
+///
+/// Header and footer bytes are left untouched; the flag byte is cleared.
+#[inline(always)]
+pub fn pack_channels(buffer: &mut [u8; SBUS_FRAME_LENGTH], channels: &[u16; CHANNEL_COUNT]) {
+    buffer[1..=CHANNEL_DATA_LENGTH].copy_from_slice(&pack_channel_data(channels));
+    buffer[SBUS_FRAME_LENGTH - 2] = 0;
+}
+
+/// Packs 16 channel values into the 22 channel data bytes of a frame
+///
+/// Inverse of [`unpack_channel_data`]; values are truncated to 11 bits.
+#[inline(always)]
+pub const fn pack_channel_data(channels: &[u16; CHANNEL_COUNT]) -> [u8; CHANNEL_DATA_LENGTH] {
+    let mut data = [0u8; CHANNEL_DATA_LENGTH];
 
     // Pack channels using the exact inverse of the parsing logic
     let ch = channels;
 
-    // Channel 1 - Bytes 1-2
-    buffer[1] = (ch[0] & 0xFF) as u8;
-    buffer[2] = ((ch[0] >> 8) & 0x07) as u8;
-
-    // Channel 2 - Bytes 2-3
-    buffer[2] |= ((ch[1] & 0x1F) << 3) as u8;
-    buffer[3] = ((ch[1] >> 5) & 0x3F) as u8;
-
-    // Channel 3 - Bytes 3-5
-    buffer[3] |= ((ch[2] & 0x03) << 6) as u8;
-    buffer[4] = ((ch[2] >> 2) & 0xFF) as u8;
-    buffer[5] = ((ch[2] >> 10) & 0x01) as u8;
-
-    // Channel 4 - Bytes 5-6
-    buffer[5] |= ((ch[3] & 0x7F) << 1) as u8;
-    buffer[6] = ((ch[3] >> 7) & 0x0F) as u8;
-
-    // Channel 5 - Bytes 6-7
-    buffer[6] |= ((ch[4] & 0x0F) << 4) as u8;
-    buffer[7] = ((ch[4] >> 4) & 0x7F) as u8;
-
-    // Channel 6 - Bytes 7-9
-    buffer[7] |= ((ch[5] & 0x01) << 7) as u8;
-    buffer[8] = ((ch[5] >> 1) & 0xFF) as u8;
-    buffer[9] = ((ch[5] >> 9) & 0x03) as u8;
-
-    // Channel 7 - Bytes 9-10
-    buffer[9] |= ((ch[6] & 0x3F) << 2) as u8;
-    buffer[10] = ((ch[6] >> 6) & 0x1F) as u8;
-
-    // Channel 8 - Bytes 10-11
-    buffer[10] |= ((ch[7] & 0x07) << 5) as u8;
-    buffer[11] = ((ch[7] >> 3) & 0xFF) as u8;
-
-    // Channel 9 - Bytes 12-13
-    buffer[12] = (ch[8] & 0xFF) as u8;
-    buffer[13] = ((ch[8] >> 8) & 0x07) as u8;
-
-    // Channel 10 - Bytes 13-14
-    buffer[13] |= ((ch[9] & 0x1F) << 3) as u8;
-    buffer[14] = ((ch[9] >> 5) & 0x3F) as u8;
-
-    // Channel 11 - Bytes 14-16
-    buffer[14] |= ((ch[10] & 0x03) << 6) as u8;
-    buffer[15] = ((ch[10] >> 2) & 0xFF) as u8;
-    buffer[16] = ((ch[10] >> 10) & 0x01) as u8;
-
-    // Channel 12 - Bytes 16-17
-    buffer[16] |= ((ch[11] & 0x7F) << 1) as u8;
-    buffer[17] = ((ch[11] >> 7) & 0x0F) as u8;
-
-    // Channel 13 - Bytes 17-18
-    buffer[17] |= ((ch[12] & 0x0F) << 4) as u8;
-    buffer[18] = ((ch[12] >> 4) & 0x7F) as u8;
-
-    // Channel 14 - Bytes 18-20
-    buffer[18] |= ((ch[13] & 0x01) << 7) as u8;
-    buffer[19] = ((ch[13] >> 1) & 0xFF) as u8;
-    buffer[20] = ((ch[13] >> 9) & 0x03) as u8;
-
-    // Channel 15 - Bytes 20-21
-    buffer[20] |= ((ch[14] & 0x3F) << 2) as u8;
-    buffer[21] = ((ch[14] >> 6) & 0x1F) as u8;
-
-    // Channel 16 - Bytes 21-22
-    buffer[21] |= ((ch[15] & 0x07) << 5) as u8;
-    buffer[22] = ((ch[15] >> 3) & 0xFF) as u8;
+    // Channel 1 - Bytes 0-1
+    data[0] = (ch[0] & 0xFF) as u8;
+    data[1] = ((ch[0] >> 8) & 0x07) as u8;
+
+    // Channel 2 - Bytes 1-2
+    data[1] |= ((ch[1] & 0x1F) << 3) as u8;
+    data[2] = ((ch[1] >> 5) & 0x3F) as u8;
+
+    // Channel 3 - Bytes 2-4
+    data[2] |= ((ch[2] & 0x03) << 6) as u8;
+    data[3] = ((ch[2] >> 2) & 0xFF) as u8;
+    data[4] = ((ch[2] >> 10) & 0x01) as u8;
+
+    // Channel 4 - Bytes 4-5
+    data[4] |= ((ch[3] & 0x7F) << 1) as u8;
+    data[5] = ((ch[3] >> 7) & 0x0F) as u8;
+
+    // Channel 5 - Bytes 5-6
+    data[5] |= ((ch[4] & 0x0F) << 4) as u8;
+    data[6] = ((ch[4] >> 4) & 0x7F) as u8;
+
+    // Channel 6 - Bytes 6-8
+    data[6] |= ((ch[5] & 0x01) << 7) as u8;
+    data[7] = ((ch[5] >> 1) & 0xFF) as u8;
+    data[8] = ((ch[5] >> 9) & 0x03) as u8;
+
+    // Channel 7 - Bytes 8-9
+    data[8] |= ((ch[6] & 0x3F) << 2) as u8;
+    data[9] = ((ch[6] >> 6) & 0x1F) as u8;
+
+    // Channel 8 - Bytes 9-10
+    data[9] |= ((ch[7] & 0x07) << 5) as u8;
+    data[10] = ((ch[7] >> 3) & 0xFF) as u8;
+
+    // Channel 9 - Bytes 11-12
+    data[11] = (ch[8] & 0xFF) as u8;
+    data[12] = ((ch[8] >> 8) & 0x07) as u8;
+
+    // Channel 10 - Bytes 12-13
+    data[12] |= ((ch[9] & 0x1F) << 3) as u8;
+    data[13] = ((ch[9] >> 5) & 0x3F) as u8;
+
+    // Channel 11 - Bytes 13-15
+    data[13] |= ((ch[10] & 0x03) << 6) as u8;
+    data[14] = ((ch[10] >> 2) & 0xFF) as u8;
+    data[15] = ((ch[10] >> 10) & 0x01) as u8;
+
+    // Channel 12 - Bytes 15-16
+    data[15] |= ((ch[11] & 0x7F) << 1) as u8;
+    data[16] = ((ch[11] >> 7) & 0x0F) as u8;
+
+    // Channel 13 - Bytes 16-17
+    data[16] |= ((ch[12] & 0x0F) << 4) as u8;
+    data[17] = ((ch[12] >> 4) & 0x7F) as u8;
+
+    // Channel 14 - Bytes 17-19
+    data[17] |= ((ch[13] & 0x01) << 7) as u8;
+    data[18] = ((ch[13] >> 1) & 0xFF) as u8;
+    data[19] = ((ch[13] >> 9) & 0x03) as u8;
+
+    // Channel 15 - Bytes 19-20
+    data[19] |= ((ch[14] & 0x3F) << 2) as u8;
+    data[20] = ((ch[14] >> 6) & 0x1F) as u8;
+
+    // Channel 16 - Bytes 20-21
+    data[20] |= ((ch[15] & 0x07) << 5) as u8;
+    data[21] = ((ch[15] >> 3) & 0xFF) as u8;
+
+    data
 }
 
 #[cfg(test)]
@@ -271,4 +483,57 @@ mod tests {
                 });
         }
     }
+
+    #[test]
+    fn test_unpack_channel_data_matches_frame() {
+        let mut channels = [0u16; CHANNEL_COUNT];
+        channels
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = 0x555 ^ (i as u16 * 129));
+        let mut buffer = [0u8; SBUS_FRAME_LENGTH];
+        pack_channels(&mut buffer, &channels);
+
+        let data: &[u8; CHANNEL_DATA_LENGTH] = buffer[1..23].try_into().unwrap();
+        assert_eq!(unpack_channel_data(data), channels);
+        assert_eq!(unpack_channels(&buffer), channels);
+    }
+
+    #[test]
+    fn test_unpack_pack_boundaries() {
+        let boundaries = [0, 1, 2, 1023, 1024, 1025, 2045, 2046, CHANNEL_MAX];
+        for &a in &boundaries {
+            for &b in &boundaries {
+                let mut channels = [a; CHANNEL_COUNT];
+                channels.iter_mut().skip(1).step_by(2).for_each(|v| *v = b);
+                let mut buffer = [0u8; SBUS_FRAME_LENGTH];
+                pack_channels(&mut buffer, &channels);
+                assert_eq!(unpack_channels(&buffer), channels);
+            }
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_unpack_pack_random(channels in proptest::array::uniform16(0..=CHANNEL_MAX)) {
+            let mut buffer = [0u8; SBUS_FRAME_LENGTH];
+            pack_channels(&mut buffer, &channels);
+            proptest::prop_assert_eq!(unpack_channels(&buffer), channels);
+        }
+    }
+
+    #[test]
+    fn test_unpack_exhaustive_per_channel() {
+        // Every value on every channel, with neighbours at both extremes
+        for neighbours in [0, CHANNEL_MAX] {
+            for idx in 0..CHANNEL_COUNT {
+                let mut channels = [neighbours; CHANNEL_COUNT];
+                for value in 0..=CHANNEL_MAX {
+                    channels[idx] = value;
+                    let data = pack_channel_data(&channels);
+                    assert_eq!(unpack_channel_data(&data), channels);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,257 @@
+//! End-to-end harness running encoded frames through a faulty link into the decoder
+use std::io::Cursor;
+use std::vec::Vec;
+
+use crate::{capture::scan_reader, packet::SbusPacket, SBUS_FRAME_LENGTH};
+
+/// Fault injected into encoded frames on their way to the decoder
+pub trait Corruptor {
+    /// Corrupts the frame numbered `index` in place; returns `true` if it changed
+    /// the frame
+    ///
+    /// The frame may be shortened but not extended.
+    fn corrupt(&mut self, index: usize, frame: &mut Vec<u8>) -> bool;
+}
+
+/// Flips one bit in every `period`-th frame, cycling through all bit positions
+///
+/// A `period` of 0 never corrupts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitFlips {
+    pub period: usize,
+}
+
+impl Corruptor for BitFlips {
+    fn corrupt(&mut self, index: usize, frame: &mut Vec<u8>) -> bool {
+        if self.period == 0 || index % self.period != 0 {
+            return false;
+        }
+        // 57 is coprime with the 200 bits of a frame, so positions spread over it
+        let bit = index / self.period * 57 % (frame.len() * 8);
+        frame[bit / 8] ^= 1 << (bit % 8);
+        true
+    }
+}
+
+/// Cuts the last bytes off every `period`-th frame, between 1 and `max_bytes`
+///
+/// A `period` of 0 never corrupts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Truncations {
+    pub period: usize,
+    pub max_bytes: usize,
+}
+
+impl Corruptor for Truncations {
+    fn corrupt(&mut self, index: usize, frame: &mut Vec<u8>) -> bool {
+        if self.period == 0 || index % self.period != 0 {
+            return false;
+        }
+        let cut = 1 + (index / self.period) % self.max_bytes.clamp(1, frame.len());
+        frame.truncate(frame.len() - cut);
+        true
+    }
+}
+
+/// Outcome of a [`Loopback`] run
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoopbackReport {
+    /// Frames sent
+    pub sent: usize,
+    /// Frames changed by the corruptor
+    pub corrupted: usize,
+    /// Uncorrupted frames decoded at their own position
+    pub recovered: usize,
+    /// Packets decoded from corrupted frames; SBUS has no checksum, so a frame with
+    /// damaged channel data still decodes, to a different packet
+    pub from_corrupted: usize,
+}
+
+/// Encodes packets, passes the frames through an optional [`Corruptor`] and decodes
+/// the resulting stream with the resynchronizing capture scanner
+///
+/// [`Loopback::run`] panics if an invariant is broken:
+///
+/// - every uncorrupted frame is decoded, at its own offset, to the packet sent
+/// - a corrupted frame yields at most one packet, and nothing is decoded at an
+///   offset that is not the start of a frame
+///
+/// These hold as long as the channel data and flag bytes of the source packets
+/// contain neither the header nor the footer byte; otherwise, a damaged frame can
+/// make the decoder lock onto a frame starting inside the data, which SBUS cannot
+/// detect.
+pub struct Loopback<'a, I> {
+    source: I,
+    corruptor: Option<&'a mut dyn Corruptor>,
+}
+
+impl<'a, I: Iterator<Item = SbusPacket>> Loopback<'a, I> {
+    pub fn new(source: I) -> Self {
+        Self {
+            source,
+            corruptor: None,
+        }
+    }
+
+    pub fn with_corruptor(mut self, corruptor: &'a mut dyn Corruptor) -> Self {
+        self.corruptor = Some(corruptor);
+        self
+    }
+
+    /// Sends up to `frames` packets of the source and checks the decoded stream
+    pub fn run(mut self, frames: usize) -> LoopbackReport {
+        let mut stream = Vec::new();
+        // Offset, packet sent and whether the frame was corrupted
+        let mut sent = Vec::new();
+        for (index, packet) in self.source.by_ref().take(frames).enumerate() {
+            let mut frame = packet.to_array().to_vec();
+            let corrupted = match self.corruptor.as_mut() {
+                Some(corruptor) => corruptor.corrupt(index, &mut frame),
+                None => false,
+            };
+            assert!(
+                frame.len() <= SBUS_FRAME_LENGTH,
+                "corruptor extended a frame"
+            );
+            sent.push((stream.len() as u64, packet, corrupted));
+            stream.extend_from_slice(&frame);
+        }
+
+        let mut report = LoopbackReport {
+            sent: sent.len(),
+            corrupted: sent.iter().filter(|(_, _, corrupted)| *corrupted).count(),
+            ..LoopbackReport::default()
+        };
+        let mut next = 0;
+        for (offset, decoded) in scan_reader(Cursor::new(stream)) {
+            let decoded = decoded.expect("reading from memory cannot fail");
+            while next < sent.len() && sent[next].0 < offset {
+                let (lost_offset, _, corrupted) = sent[next];
+                assert!(
+                    corrupted,
+                    "uncorrupted frame {} at offset {} was not decoded",
+                    next, lost_offset
+                );
+                next += 1;
+            }
+            let (frame_offset, packet, corrupted) = *sent
+                .get(next)
+                .filter(|(frame_offset, ..)| *frame_offset == offset)
+                .unwrap_or_else(|| panic!("packet decoded at offset {} inside a frame", offset));
+            if corrupted {
+                report.from_corrupted += 1;
+            } else {
+                assert_eq!(
+                    decoded, packet,
+                    "frame {} at offset {} decoded to a different packet",
+                    next, frame_offset
+                );
+                report.recovered += 1;
+            }
+            next += 1;
+        }
+        for (index, (offset, _, corrupted)) in sent.iter().enumerate().skip(next) {
+            assert!(
+                corrupted,
+                "uncorrupted frame {} at offset {} was not decoded",
+                index, offset
+            );
+        }
+        assert_eq!(report.recovered, report.sent - report.corrupted);
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Flags, SBUS_FOOTER, SBUS_HEADER};
+
+    /// Packets whose frames carry no header or footer byte besides their own
+    fn source() -> impl Iterator<Item = SbusPacket> {
+        (0..).map(|i: u16| SbusPacket {
+            channels: core::array::from_fn(|ch| 0x555 ^ ((i + ch as u16) % 64)),
+            flags: Flags::from_byte(0b0101),
+        })
+    }
+
+    #[test]
+    fn test_source_is_unambiguous() {
+        for packet in source().take(64) {
+            let frame = packet.to_array();
+            assert!(!frame[1..SBUS_FRAME_LENGTH - 1]
+                .iter()
+                .any(|&b| b == SBUS_HEADER || b == SBUS_FOOTER));
+        }
+    }
+
+    #[test]
+    fn test_clean_link() {
+        let report = Loopback::new(source()).run(200);
+        assert_eq!(
+            report,
+            LoopbackReport {
+                sent: 200,
+                corrupted: 0,
+                recovered: 200,
+                from_corrupted: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_bit_flips() {
+        let mut flips = BitFlips { period: 3 };
+        let report = Loopback::new(source()).with_corruptor(&mut flips).run(300);
+        assert_eq!(report.corrupted, 100);
+        assert_eq!(report.recovered, 200);
+        // Flips in the channel data or flags go unnoticed, in the header or footer
+        // they drop the frame
+        assert!(report.from_corrupted > 0 && report.from_corrupted < 100);
+    }
+
+    #[test]
+    fn test_truncations() {
+        let mut truncations = Truncations {
+            period: 2,
+            max_bytes: SBUS_FRAME_LENGTH - 1,
+        };
+        let report = Loopback::new(source())
+            .with_corruptor(&mut truncations)
+            .run(100);
+        assert_eq!(report.corrupted, 50);
+        assert_eq!(report.recovered, 50);
+        assert_eq!(report.from_corrupted, 0);
+    }
+
+    #[test]
+    fn test_zero_period_never_corrupts() {
+        let mut flips = BitFlips { period: 0 };
+        let mut truncations = Truncations {
+            period: 0,
+            max_bytes: 1,
+        };
+        for corruptor in [&mut flips as &mut dyn Corruptor, &mut truncations] {
+            let report = Loopback::new(source()).with_corruptor(corruptor).run(10);
+            assert_eq!(report.corrupted, 0);
+            assert_eq!(report.recovered, 10);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "was not decoded")]
+    fn test_detects_lost_frame() {
+        struct Unreported;
+        impl Corruptor for Unreported {
+            fn corrupt(&mut self, index: usize, frame: &mut Vec<u8>) -> bool {
+                if index == 5 {
+                    frame[0] = 0;
+                }
+                false
+            }
+        }
+        Loopback::new(source())
+            .with_corruptor(&mut Unreported)
+            .run(10);
+    }
+}
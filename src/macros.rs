@@ -0,0 +1,28 @@
+//! Macros for building frames at compile time
+
+/// Builds a raw SBUS frame from 16 channel values and a flag byte at compile time
+///
+/// The channels are given as a `[u16; 16]` array expression and the flags in the
+/// layout of the frame's flag byte. Channel values are truncated to 11 bits.
+///
+/// ```rust
+/// use sbus_rs::{sbus_frame, SbusPacket, SBUS_FRAME_LENGTH};
+///
+/// const CENTER: [u8; SBUS_FRAME_LENGTH] = sbus_frame!([1024; 16], 0b1000);
+///
+/// let packet = SbusPacket::from_array(&CENTER).unwrap();
+/// assert_eq!(packet.channels, [1024; 16]);
+/// assert!(packet.flags.failsafe);
+/// ```
+#[macro_export]
+macro_rules! sbus_frame {
+    ($channels:expr, $flags:expr $(,)?) => {
+        const {
+            $crate::SbusPacket {
+                channels: $channels,
+                flags: $crate::Flags::from_byte($flags),
+            }
+            .to_array()
+        }
+    };
+}
@@ -0,0 +1,150 @@
+//! OpenSBUS 32-channel packets carried in pairs of SBUS frames
+use crate::{
+    packet::{Flags, SbusPacket},
+    SbusError, CHANNEL_COUNT, SBUS_FRAME_LENGTH,
+};
+
+/// Number of channels in an OpenSBUS packet
+pub const OPENSBUS_CHANNEL_COUNT: usize = 2 * CHANNEL_COUNT;
+
+/// Packet of the proposed OpenSBUS extension, sent as two consecutive SBUS frames
+///
+/// The first frame carries channels 0-15 and the second channels 16-31.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OpenSbusPacket {
+    pub channels: [u16; OPENSBUS_CHANNEL_COUNT],
+    /// Flag byte, in the layout of [`Flags::to_byte`]
+    pub flags: u8,
+}
+
+impl OpenSbusPacket {
+    /// Joins the channels of two consecutive packets
+    ///
+    /// A flag is set if it is set in either packet, so a failsafe reported in
+    /// only one of the frames is not lost.
+    pub fn from_sbus_pair(first: &SbusPacket, second: &SbusPacket) -> OpenSbusPacket {
+        let mut channels = [0; OPENSBUS_CHANNEL_COUNT];
+        channels[..CHANNEL_COUNT].copy_from_slice(&first.channels);
+        channels[CHANNEL_COUNT..].copy_from_slice(&second.channels);
+        OpenSbusPacket {
+            channels,
+            flags: first.flags.to_byte() | second.flags.to_byte(),
+        }
+    }
+
+    /// Splits the packet into the two SBUS packets that carry it, both with the
+    /// packet's flags
+    pub fn split_to_sbus(&self) -> (SbusPacket, SbusPacket) {
+        let flags = Flags::from_byte(self.flags);
+        let half = |offset: usize| SbusPacket {
+            channels: core::array::from_fn(|i| self.channels[offset + i]),
+            flags,
+        };
+        (half(0), half(CHANNEL_COUNT))
+    }
+}
+
+/// Pairs consecutive SBUS frames into [`OpenSbusPacket`]s
+///
+/// The frames carry no marker telling the halves apart, so the parser relies on
+/// seeing the first half first: call [`OpenSbusStreamingParser::reset`] when the
+/// sender starts a new packet. An invalid frame discards a buffered first half.
+#[derive(Debug, Default, Clone)]
+pub struct OpenSbusStreamingParser {
+    first: Option<SbusPacket>,
+}
+
+impl OpenSbusStreamingParser {
+    pub const fn new() -> Self {
+        Self { first: None }
+    }
+
+    /// Feeds the next decoded packet, returning a packet once both halves arrived
+    pub fn push_packet(&mut self, packet: &SbusPacket) -> Option<OpenSbusPacket> {
+        match self.first.take() {
+            Some(first) => Some(OpenSbusPacket::from_sbus_pair(&first, packet)),
+            None => {
+                self.first = Some(*packet);
+                None
+            }
+        }
+    }
+
+    /// Decodes and feeds the next raw frame
+    pub fn push_frame(
+        &mut self,
+        frame: &[u8; SBUS_FRAME_LENGTH],
+    ) -> Result<Option<OpenSbusPacket>, SbusError> {
+        match SbusPacket::from_array(frame) {
+            Ok(packet) => Ok(self.push_packet(&packet)),
+            Err(err) => {
+                self.reset();
+                Err(err)
+            }
+        }
+    }
+
+    /// Discards a buffered first half
+    pub fn reset(&mut self) {
+        self.first = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet() -> OpenSbusPacket {
+        OpenSbusPacket {
+            channels: core::array::from_fn(|i| i as u16 * 64),
+            flags: 0b0100,
+        }
+    }
+
+    #[test]
+    fn test_split_and_join() {
+        let (first, second) = packet().split_to_sbus();
+        assert_eq!(first.channels[15], 15 * 64);
+        assert_eq!(second.channels[0], 16 * 64);
+        assert!(first.flags.frame_lost && second.flags.frame_lost);
+        assert_eq!(OpenSbusPacket::from_sbus_pair(&first, &second), packet());
+    }
+
+    #[test]
+    fn test_flags_are_merged() {
+        let (first, mut second) = packet().split_to_sbus();
+        second.flags.failsafe = true;
+        let joined = OpenSbusPacket::from_sbus_pair(&first, &second);
+        assert_eq!(joined.flags, 0b1100);
+    }
+
+    #[test]
+    fn test_streaming_parser_pairs_frames() {
+        let (first, second) = packet().split_to_sbus();
+        let mut parser = OpenSbusStreamingParser::new();
+        for _ in 0..3 {
+            assert_eq!(parser.push_frame(&first.to_array()), Ok(None));
+            assert_eq!(parser.push_frame(&second.to_array()), Ok(Some(packet())));
+        }
+    }
+
+    #[test]
+    fn test_streaming_parser_resyncs() {
+        let (first, second) = packet().split_to_sbus();
+        let mut parser = OpenSbusStreamingParser::new();
+        assert_eq!(parser.push_packet(&first), None);
+        let mut corrupted = second.to_array();
+        corrupted[0] = 0xFF;
+        assert_eq!(
+            parser.push_frame(&corrupted),
+            Err(SbusError::InvalidHeader(0xFF))
+        );
+        assert_eq!(parser.push_packet(&first), None);
+        assert_eq!(parser.push_packet(&second), Some(packet()));
+
+        assert_eq!(parser.push_packet(&first), None);
+        parser.reset();
+        assert_eq!(parser.push_packet(&first), None);
+        assert_eq!(parser.push_packet(&second), Some(packet()));
+    }
+}
@@ -0,0 +1,133 @@
+//! Compact in-memory storage of packets in the wire bit-packing
+use crate::{
+    pack_channel_data,
+    packet::{Flags, SbusPacket},
+    unpack_channel_data, unpack_one, CHANNEL_COUNT, CHANNEL_DATA_LENGTH,
+};
+
+/// Packet stored as the 22 channel data bytes of a frame followed by the flag byte
+///
+/// Takes 23 bytes instead of the 36 of an [`SbusPacket`], which adds up when keeping
+/// a long packet history in RAM. The price is CPU time: every channel read shifts
+/// and masks the packed bits, so code that reads many channels of the same packet
+/// should convert it once with [`PackedSbusPacket::to_packet`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedSbusPacket([u8; CHANNEL_DATA_LENGTH + 1]);
+
+impl PackedSbusPacket {
+    /// Packs a packet; channel values are truncated to 11 bits
+    pub const fn from_packet(packet: &SbusPacket) -> Self {
+        let data = pack_channel_data(&packet.channels);
+        let mut bytes = [0u8; CHANNEL_DATA_LENGTH + 1];
+        let mut i = 0;
+        while i < CHANNEL_DATA_LENGTH {
+            bytes[i] = data[i];
+            i += 1;
+        }
+        bytes[CHANNEL_DATA_LENGTH] = packet.flags.to_byte();
+        Self(bytes)
+    }
+
+    pub fn to_packet(&self) -> SbusPacket {
+        let (data, _) = self.0.split_first_chunk::<CHANNEL_DATA_LENGTH>().unwrap();
+        SbusPacket {
+            channels: unpack_channel_data(data),
+            flags: self.flags(),
+        }
+    }
+
+    /// Extracts the value of channel `idx`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not below [`CHANNEL_COUNT`].
+    pub fn channel(&self, idx: usize) -> u16 {
+        assert!(idx < CHANNEL_COUNT, "channel index {} out of range", idx);
+        unpack_one(&self.0, idx)
+    }
+
+    pub fn flags(&self) -> Flags {
+        Flags::from_byte(self.0[CHANNEL_DATA_LENGTH])
+    }
+
+    /// Returns the packed bytes
+    pub fn as_bytes(&self) -> &[u8; CHANNEL_DATA_LENGTH + 1] {
+        &self.0
+    }
+}
+
+impl From<&SbusPacket> for PackedSbusPacket {
+    fn from(packet: &SbusPacket) -> Self {
+        Self::from_packet(packet)
+    }
+}
+
+impl From<PackedSbusPacket> for SbusPacket {
+    fn from(packed: PackedSbusPacket) -> Self {
+        packed.to_packet()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_size() {
+        assert_eq!(core::mem::size_of::<PackedSbusPacket>(), 23);
+        assert!(core::mem::size_of::<PackedSbusPacket>() < core::mem::size_of::<SbusPacket>());
+    }
+
+    #[test]
+    fn test_round_trip_boundaries() {
+        for value in [0, 1, CHANNEL_MID, CHANNEL_MAX - 1, CHANNEL_MAX] {
+            for flags in 0..=0x0F {
+                let packet = SbusPacket {
+                    channels: [value; CHANNEL_COUNT],
+                    flags: Flags::from_byte(flags),
+                };
+                let packed = PackedSbusPacket::from_packet(&packet);
+                assert_eq!(packed.to_packet(), packet);
+                assert_eq!(packed.flags(), packet.flags);
+            }
+        }
+    }
+
+    #[test]
+    fn test_channel_random_access() {
+        for neighbours in [0, CHANNEL_MAX] {
+            for idx in 0..CHANNEL_COUNT {
+                for value in [0, 1, 0x2AA, 0x555, CHANNEL_MAX] {
+                    let mut packet = SbusPacket {
+                        channels: [neighbours; CHANNEL_COUNT],
+                        flags: Flags::from_byte(0x0F),
+                    };
+                    packet.channels[idx] = value;
+                    let packed = PackedSbusPacket::from(&packet);
+                    for (i, &expected) in packet.channels.iter().enumerate() {
+                        assert_eq!(packed.channel(i), expected);
+                    }
+                    assert_eq!(SbusPacket::from(packed), packet);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_frame_data() {
+        let packet = SbusPacket {
+            channels: core::array::from_fn(|i| i as u16 * 131),
+            flags: Flags::from_byte(0b0101),
+        };
+        let frame = packet.to_array();
+        let packed = PackedSbusPacket::from_packet(&packet);
+        assert_eq!(packed.as_bytes()[..], frame[1..=CHANNEL_DATA_LENGTH + 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "channel index 16 out of range")]
+    fn test_channel_out_of_range() {
+        PackedSbusPacket::default().channel(16);
+    }
+}
@@ -1,13 +1,32 @@
-use crate::{channels_parsing, SbusError, SBUS_FOOTER, SBUS_FRAME_LENGTH, SBUS_HEADER};
+//! Decoded SBUS packet and flags
+use crate::{
+    pack_channel_data, unpack_channels, SbusError, CHANNEL_DATA_LENGTH, CHANNEL_MAX, CHANNEL_MID,
+    SBUS_FOOTER, SBUS_FRAME_LENGTH, SBUS_HEADER,
+};
 
 /// Represents a complete SBUS packet with channel data and flags
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct SbusPacket {
     pub channels: [u16; 16],
     pub flags: Flags,
 }
 
 impl SbusPacket {
+    /// Number of bits per channel value
+    pub const fn channel_resolution_bits() -> u8 {
+        CHANNEL_MAX.count_ones() as u8
+    }
+
+    /// Maximum channel value, same as [`CHANNEL_MAX`]
+    pub const fn max_channel_value() -> u16 {
+        CHANNEL_MAX
+    }
+
+    /// Midpoint of the channel range, same as [`CHANNEL_MID`]
+    pub const fn midpoint_value() -> u16 {
+        CHANNEL_MID
+    }
+
     /// Creates a new SbusPacket from a raw 25-byte SBUS frame
     ///
     /// # Arguments
@@ -18,17 +37,55 @@ impl SbusPacket {
     ///
     /// * `Ok(SbusPacket)` if the frame is valid
     /// * `Err(SbusError)` if the frame has invalid header or footer
-    pub fn from_array(buffer: &[u8; SBUS_FRAME_LENGTH]) -> Result<Self, SbusError> {
-        SbusPacket::validate_frame(buffer)?;
+    pub const fn from_array(buffer: &[u8; SBUS_FRAME_LENGTH]) -> Result<Self, SbusError> {
+        if let Err(e) = SbusPacket::validate_frame(buffer) {
+            return Err(e);
+        }
+        Ok(Self::from_validated_array(buffer))
+    }
+
+    /// Decodes a frame whose header and footer the caller already checked
+    pub(crate) const fn from_validated_array(buffer: &[u8; SBUS_FRAME_LENGTH]) -> Self {
+        debug_assert!(SbusPacket::validate_frame(buffer).is_ok());
 
         // Parse channels and flags
-        let channels = channels_parsing(buffer);
+        let channels = unpack_channels(buffer);
         let flags = Flags::from_byte(buffer[23]);
 
-        Ok(Self { channels, flags })
+        Self { channels, flags }
+    }
+
+    /// Encodes the packet into a raw 25-byte SBUS frame
+    ///
+    /// Channel values are truncated to 11 bits.
+    pub const fn to_array(&self) -> [u8; SBUS_FRAME_LENGTH] {
+        let mut buffer = [0u8; SBUS_FRAME_LENGTH];
+        buffer[0] = SBUS_HEADER;
+        let data = pack_channel_data(&self.channels);
+        let mut i = 0;
+        while i < CHANNEL_DATA_LENGTH {
+            buffer[1 + i] = data[i];
+            i += 1;
+        }
+        buffer[23] = self.flags.to_byte();
+        buffer[SBUS_FRAME_LENGTH - 1] = SBUS_FOOTER;
+        buffer
+    }
+
+    /// Appends `count` copies of the encoded frame to `out`
+    ///
+    /// The packet is encoded once, which makes this cheaper than calling
+    /// [`SbusPacket::to_array`] for every frame of a simulated burst.
+    #[cfg(feature = "std")]
+    pub fn encode_repeated(&self, count: usize, out: &mut std::vec::Vec<u8>) {
+        let frame = self.to_array();
+        out.reserve(count * SBUS_FRAME_LENGTH);
+        for _ in 0..count {
+            out.extend_from_slice(&frame);
+        }
     }
     /// Validates if header and footer and set correctly
-    pub fn validate_frame(frame_buf: &[u8; SBUS_FRAME_LENGTH]) -> Result<(), SbusError> {
+    pub const fn validate_frame(frame_buf: &[u8; SBUS_FRAME_LENGTH]) -> Result<(), SbusError> {
         let header = frame_buf[0];
         let footer = frame_buf[SBUS_FRAME_LENGTH - 1];
 
@@ -41,10 +98,113 @@ impl SbusPacket {
             Ok(())
         }
     }
+
+    /// Returns `true` if every channel value lies within `[min, max]` (inclusive)
+    pub fn channels_in_range(&self, min: u16, max: u16) -> bool {
+        self.channels
+            .iter()
+            .all(|&value| (min..=max).contains(&value))
+    }
+
+    /// Asserts that every channel value lies within `[min, max]` (inclusive)
+    ///
+    /// Intended for integration tests and hardware-in-the-loop harnesses.
+    ///
+    /// # Panics
+    ///
+    /// Panics on the first channel outside the range, reporting its index and value.
+    #[track_caller]
+    pub fn assert_channels_in_range(&self, min: u16, max: u16) {
+        for (i, &value) in self.channels.iter().enumerate() {
+            assert!(
+                (min..=max).contains(&value),
+                "Channel {} value {} is outside of the expected range [{}, {}]",
+                i,
+                value,
+                min,
+                max
+            );
+        }
+    }
+
+    /// Merges two packets channel by channel
+    ///
+    /// Bit `i` of `mask` selects `overrides.channels[i]` instead of this packet's
+    /// channel `i`. Flags are taken from this packet.
+    pub fn merged(&self, overrides: &SbusPacket, mask: u16) -> SbusPacket {
+        self.merged_with_flags(overrides, mask, self.flags)
+    }
+
+    /// Same as [`SbusPacket::merged`], but with explicitly provided flags
+    pub fn merged_with_flags(&self, overrides: &SbusPacket, mask: u16, flags: Flags) -> SbusPacket {
+        let mut channels = self.channels;
+        channels
+            .iter_mut()
+            .zip(overrides.channels)
+            .enumerate()
+            .filter(|(i, _)| mask & (1 << i) != 0)
+            .for_each(|(_, (value, override_value))| *value = override_value);
+
+        SbusPacket { channels, flags }
+    }
+
+    /// Replaces every channel for which `overrides` holds a value
+    ///
+    /// Flags are taken from this packet.
+    pub fn with_overrides(&self, overrides: &[Option<u16>; 16]) -> SbusPacket {
+        let mut channels = self.channels;
+        channels
+            .iter_mut()
+            .zip(overrides)
+            .for_each(|(value, override_value)| *value = override_value.unwrap_or(*value));
+
+        SbusPacket {
+            channels,
+            flags: self.flags,
+        }
+    }
+
+    /// Replaces the `gated_channels` with `gate_value` while the switch channel is
+    /// above `threshold`
+    ///
+    /// Otherwise the packet is returned unchanged. Flags are taken from this packet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `switch_ch` or any of the `gated_channels` is not below
+    /// `CHANNEL_COUNT`.
+    pub fn gate_by_switch(
+        &self,
+        switch_ch: usize,
+        threshold: u16,
+        gated_channels: &[usize],
+        gate_value: u16,
+    ) -> SbusPacket {
+        let mut gated = *self;
+        if self.channels[switch_ch] > threshold {
+            for &idx in gated_channels {
+                gated.channels[idx] = gate_value;
+            }
+        }
+        gated
+    }
+
+    /// Returns a bitmask of the flags that differ between the two packets
+    ///
+    /// Bit 0 is `d1`, bit 1 `d2`, bit 2 `frame_lost` and bit 3 `failsafe`, matching
+    /// the layout of the flag byte in the frame.
+    pub fn diff_flags(&self, other: &SbusPacket) -> u8 {
+        self.flags.to_byte() ^ other.flags.to_byte()
+    }
+
+    /// Returns `true` if any flag differs between the two packets
+    pub fn flags_changed(&self, other: &SbusPacket) -> bool {
+        self.diff_flags(other) != 0
+    }
 }
 
 /// Status flags contained in an SBUS frame
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Flags {
     pub d1: bool,
     pub d2: bool,
@@ -53,13 +213,7 @@ pub struct Flags {
 }
 
 impl Flags {
-    pub fn from_byte(flag_byte: u8) -> Self {
-        Flags::from(flag_byte)
-    }
-}
-
-impl From<u8> for Flags {
-    fn from(flag_byte: u8) -> Self {
+    pub const fn from_byte(flag_byte: u8) -> Self {
         Self {
             d1: (flag_byte & (1 << 0)) != 0,
             d2: (flag_byte & (1 << 1)) != 0,
@@ -67,4 +221,229 @@ impl From<u8> for Flags {
             failsafe: (flag_byte & (1 << 3)) != 0,
         }
     }
+
+    pub const fn to_byte(self) -> u8 {
+        (self.d1 as u8)
+            | ((self.d2 as u8) << 1)
+            | ((self.frame_lost as u8) << 2)
+            | ((self.failsafe as u8) << 3)
+    }
+}
+
+impl From<u8> for Flags {
+    fn from(flag_byte: u8) -> Self {
+        Flags::from_byte(flag_byte)
+    }
+}
+
+impl From<Flags> for u8 {
+    fn from(flags: Flags) -> Self {
+        flags.to_byte()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet_with(channels: [u16; 16]) -> SbusPacket {
+        SbusPacket {
+            channels,
+            flags: Flags::from_byte(0),
+        }
+    }
+
+    #[test]
+    fn test_channels_in_range() {
+        let packet = packet_with([1000; 16]);
+        assert!(packet.channels_in_range(1000, 1000));
+        assert!(packet.channels_in_range(0, CHANNEL_MAX));
+        assert!(!packet.channels_in_range(1001, CHANNEL_MAX));
+        assert!(!packet.channels_in_range(0, 999));
+    }
+
+    #[test]
+    fn test_assert_channels_in_range_bounds() {
+        let mut channels = [172u16; 16];
+        channels[15] = 1811;
+        packet_with(channels).assert_channels_in_range(172, 1811);
+    }
+
+    #[test]
+    #[should_panic(expected = "Channel 5 value 1812 is outside of the expected range [172, 1811]")]
+    fn test_assert_channels_in_range_panics_above_max() {
+        let mut channels = [992u16; 16];
+        channels[5] = 1811 + 1;
+        packet_with(channels).assert_channels_in_range(172, 1811);
+    }
+
+    #[test]
+    fn test_to_array_round_trip() {
+        let mut channels = [0u16; 16];
+        channels
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, val)| *val = i as u16 * 128);
+        for flag_byte in 0..=0x0F {
+            let packet = SbusPacket {
+                channels,
+                flags: Flags::from_byte(flag_byte),
+            };
+            let frame = packet.to_array();
+            assert_eq!(frame[23], flag_byte);
+            assert_eq!(SbusPacket::from_array(&frame), Ok(packet));
+        }
+    }
+
+    #[test]
+    fn test_merged_mask_bits() {
+        let base = packet_with([100; 16]);
+        let overrides = SbusPacket {
+            channels: [200; 16],
+            flags: Flags::from_byte(0b1111),
+        };
+
+        assert_eq!(base.merged(&overrides, 0), base);
+        assert_eq!(base.merged(&overrides, u16::MAX).channels, [200; 16]);
+
+        for bit in 0..16 {
+            let merged = base.merged(&overrides, 1 << bit);
+            for (i, &value) in merged.channels.iter().enumerate() {
+                let expected = if i == bit { 200 } else { 100 };
+                assert_eq!(value, expected, "Channel {} wrong for mask bit {}", i, bit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_merged_flags() {
+        let base = packet_with([100; 16]);
+        let overrides = SbusPacket {
+            channels: [200; 16],
+            flags: Flags::from_byte(0b1000),
+        };
+
+        assert_eq!(base.merged(&overrides, u16::MAX).flags, base.flags);
+        let explicit = Flags::from_byte(0b0011);
+        assert_eq!(
+            base.merged_with_flags(&overrides, u16::MAX, explicit).flags,
+            explicit
+        );
+    }
+
+    #[test]
+    fn test_with_overrides() {
+        let base = SbusPacket {
+            channels: [100; 16],
+            flags: Flags::from_byte(0b0100),
+        };
+        let mut overrides = [None; 16];
+        overrides[3] = Some(1811);
+        overrides[15] = Some(172);
+
+        let merged = base.with_overrides(&overrides);
+        assert_eq!(merged.channels[3], 1811);
+        assert_eq!(merged.channels[15], 172);
+        assert_eq!(merged.channels.iter().filter(|&&v| v == 100).count(), 14);
+        assert_eq!(merged.flags, base.flags);
+    }
+
+    #[test]
+    fn test_gate_by_switch_threshold() {
+        let mut packet = packet_with([CHANNEL_MID; 16]);
+        packet.flags.d1 = true;
+        for (switch, gated) in [
+            (1499, false),
+            (1500, false),
+            (1501, true),
+            (CHANNEL_MAX, true),
+        ] {
+            packet.channels[4] = switch;
+            let result = packet.gate_by_switch(4, 1500, &[0, 3], 0);
+            let expected = if gated { 0 } else { CHANNEL_MID };
+            assert_eq!(result.channels[0], expected);
+            assert_eq!(result.channels[3], expected);
+            assert_eq!(result.channels[1], CHANNEL_MID);
+            assert_eq!(result.channels[4], switch);
+            assert_eq!(result.flags, packet.flags);
+        }
+    }
+
+    #[test]
+    fn test_gate_by_switch_can_gate_itself() {
+        let packet = packet_with([CHANNEL_MAX; 16]);
+        assert_eq!(packet.gate_by_switch(2, 0, &[2], 5).channels[2], 5);
+        assert_eq!(packet.gate_by_switch(2, 0, &[], 5), packet);
+        assert_eq!(packet.gate_by_switch(2, CHANNEL_MAX, &[0, 1], 5), packet);
+    }
+
+    #[test]
+    fn test_diff_flags_transitions() {
+        let with_flags = |flag_byte| SbusPacket {
+            flags: Flags::from_byte(flag_byte),
+            ..SbusPacket::default()
+        };
+
+        // Every combination of changed bits, applied to every starting state
+        for changed in 0..=0x0Fu8 {
+            for start in 0..=0x0Fu8 {
+                let before = with_flags(start);
+                let after = with_flags(start ^ changed);
+                let diff = before.diff_flags(&after);
+
+                assert_eq!(diff, changed);
+                assert_eq!(diff & 0x01 != 0, before.flags.d1 != after.flags.d1);
+                assert_eq!(diff & 0x02 != 0, before.flags.d2 != after.flags.d2);
+                assert_eq!(
+                    diff & 0x04 != 0,
+                    before.flags.frame_lost != after.flags.frame_lost
+                );
+                assert_eq!(
+                    diff & 0x08 != 0,
+                    before.flags.failsafe != after.flags.failsafe
+                );
+                assert_eq!(before.flags_changed(&after), changed != 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_diff_flags_ignores_channels() {
+        let before = packet_with([0; 16]);
+        let after = packet_with([CHANNEL_MAX; 16]);
+        assert_eq!(before.diff_flags(&after), 0);
+        assert!(!before.flags_changed(&after));
+    }
+
+    #[test]
+    fn test_channel_resolution_consts() {
+        const BITS: u8 = SbusPacket::channel_resolution_bits();
+        const MAX: u16 = SbusPacket::max_channel_value();
+        assert_eq!(BITS, 11);
+        assert_eq!(MAX, 2047);
+        assert_eq!(MAX, (1 << BITS) - 1);
+        assert_eq!(SbusPacket::midpoint_value(), 1024);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_encode_repeated() {
+        let packet = SbusPacket {
+            channels: [172, 1811, 992, 0, 2047, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+            flags: Flags::from_byte(0b0110),
+        };
+        let mut out = vec![0xAA];
+        packet.encode_repeated(5, &mut out);
+        assert_eq!(out.len(), 1 + 5 * SBUS_FRAME_LENGTH);
+        for frame in out[1..].chunks_exact(SBUS_FRAME_LENGTH) {
+            assert_eq!(
+                SbusPacket::from_array(frame.try_into().unwrap()),
+                Ok(packet)
+            );
+        }
+
+        out.clear();
+        packet.encode_repeated(0, &mut out);
+        assert!(out.is_empty());
+    }
 }
@@ -2,7 +2,7 @@
 use core::marker::PhantomData;
 
 #[cfg(feature = "async")]
-mod asynch;
+pub mod asynch;
 
 #[cfg(feature = "async")]
 pub use asynch::SbusParserAsync;
@@ -31,5 +31,9 @@ pub const SBUS_FOOTER: u8 = 0x00;
 pub const SBUS_FRAME_LENGTH: usize = 25;
 /// The number of channels in a SBus Frame.
 pub const CHANNEL_COUNT: usize = 16;
+/// Number of channel data bytes between header and flags
+pub const CHANNEL_DATA_LENGTH: usize = 22;
 /// The maximum value of a channel.
 pub const CHANNEL_MAX: u16 = 0x07FF; // 11 bits max = 2047
+/// The midpoint of the 11-bit channel range.
+pub const CHANNEL_MID: u16 = 1024;
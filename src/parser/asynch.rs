@@ -80,33 +80,7 @@ mod tests {
     use crate::parser::asynch::SbusParserAsync;
     use embedded_io_adapters::tokio_1::FromTokio;
 
-    const TEST_PACKET: [u8; 25] = [
-        0x0F, // HEAD_BYTE
-        (1024 & 0x07FF) as u8,
-        (((1024 & 0x07FF) >> 8) | ((1024 & 0x07FF) << 3)) as u8,
-        (((1024 & 0x07FF) >> 5) | ((1024 & 0x07FF) << 6)) as u8,
-        ((1024 & 0x07FF) >> 2) as u8,
-        (((1024 & 0x07FF) >> 10) | ((1024 & 0x07FF) << 1)) as u8,
-        (((1024 & 0x07FF) >> 7) | ((1024 & 0x07FF) << 4)) as u8,
-        ((1024 & 0x07FF) >> 4) as u8,
-        ((1024 & 0x07FF) << 2) as u8,
-        (((1024 & 0x07FF) >> 8) | ((1024 & 0x07FF) << 5)) as u8,
-        ((1024 & 0x07FF) >> 1) as u8,
-        (((1024 & 0x07FF) >> 9) | ((1024 & 0x07FF) << 6)) as u8,
-        ((1024 & 0x07FF) >> 3) as u8,
-        (((1024 & 0x07FF) >> 10) | ((1024 & 0x07FF) << 1)) as u8,
-        (((1024 & 0x07FF) >> 7) | ((1024 & 0x07FF) << 4)) as u8,
-        ((1024 & 0x07FF) >> 4) as u8,
-        ((1024 & 0x07FF) << 2) as u8,
-        (((1024 & 0x07FF) >> 8) | ((1024 & 0x07FF) << 5)) as u8,
-        ((1024 & 0x07FF) >> 1) as u8,
-        (((1024 & 0x07FF) >> 9) | ((1024 & 0x07FF) << 6)) as u8,
-        ((1024 & 0x07FF) >> 3) as u8,
-        (((1024 & 0x07FF) >> 10) | ((1024 & 0x07FF) << 1)) as u8,
-        (((1024 & 0x07FF) >> 7) | ((1024 & 0x07FF) << 4)) as u8,
-        0x00, // FLAGS_BYTE, no flags set
-        0x00, // FOOT_BYTE
-    ];
+    const TEST_PACKET: [u8; 25] = crate::sbus_frame!([1024; 16], 0x00);
 
     #[tokio::test]
     async fn test_valid_sbus_frame_async() {
@@ -0,0 +1,21 @@
+//! Recommended imports for typical SBUS code
+//!
+//! ```rust
+//! use sbus_rs::prelude::*;
+//!
+//! let packet = SbusPacket::default();
+//! assert!(packet.channels_in_range(0, CHANNEL_MAX));
+//! ```
+pub use crate::{
+    channel::{ChannelAssignment, ChannelId},
+    error::SbusError,
+    packet::{Flags, SbusPacket},
+    parser::{
+        CHANNEL_COUNT, CHANNEL_MAX, CHANNEL_MID, SBUS_FOOTER, SBUS_FRAME_LENGTH, SBUS_HEADER,
+    },
+};
+
+#[cfg(feature = "blocking")]
+pub use crate::parser::SbusParser;
+#[cfg(feature = "async")]
+pub use crate::parser::SbusParserAsync;
@@ -0,0 +1,155 @@
+//! Servo output timer compare values
+use crate::{
+    channel::ChannelAssignment,
+    error::PwmConfigError,
+    packet::SbusPacket,
+    ranges::US_STANDARD_MAX,
+    units::{Ticks, Us},
+    CHANNEL_COUNT,
+};
+
+/// Converts pulse widths into compare values of a PWM timer
+///
+/// Configured with the timer's tick frequency and the PWM frequency; the period
+/// is `tick_hz / pwm_hz` ticks, rounded down as a timer's auto-reload value is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PwmMapper {
+    tick_hz: u32,
+    period: u32,
+}
+
+impl PwmMapper {
+    /// Creates a mapper for a timer counting at `tick_hz` with a PWM frequency of
+    /// `pwm_hz`
+    ///
+    /// # Errors
+    ///
+    /// Fails if either frequency is zero, or if a pulse of [`US_STANDARD_MAX`]
+    /// does not fit in the period.
+    pub const fn new(tick_hz: u32, pwm_hz: u32) -> Result<Self, PwmConfigError> {
+        if tick_hz == 0 || pwm_hz == 0 {
+            return Err(PwmConfigError::ZeroFrequency);
+        }
+        let mapper = Self {
+            tick_hz,
+            period: tick_hz / pwm_hz,
+        };
+        let max_compare = mapper.ticks(US_STANDARD_MAX);
+        if max_compare > mapper.period {
+            return Err(PwmConfigError::PeriodTooShort {
+                period: mapper.period,
+                max_compare,
+            });
+        }
+        Ok(mapper)
+    }
+
+    /// Timer period in ticks
+    pub const fn period(&self) -> u32 {
+        self.period
+    }
+
+    /// Returns the compare value of a pulse of `ch_us`
+    ///
+    /// The pulse is converted to ticks rounding half up, and capped at the period.
+    pub const fn compare(&self, ch_us: Us) -> u32 {
+        let ticks = self.ticks(ch_us.0);
+        if ticks > self.period {
+            self.period
+        } else {
+            ticks
+        }
+    }
+
+    const fn ticks(&self, us: u16) -> u32 {
+        ((us as u64 * self.tick_hz as u64 + 500_000) / 1_000_000) as u32
+    }
+
+    /// Returns the compare values of all channels, ordered by function
+    ///
+    /// Outputs 0-3 carry roll, pitch, throttle and yaw under `assignment`, and
+    /// outputs 4-15 the remaining channels in ascending order, as in
+    /// [`SbusPacket::named`].
+    pub fn map_packet(
+        &self,
+        packet: &SbusPacket,
+        assignment: &ChannelAssignment,
+    ) -> [u32; CHANNEL_COUNT] {
+        let named = packet.named(assignment);
+        let mut outputs = [0; CHANNEL_COUNT];
+        for (output, ticks) in outputs.iter_mut().zip(
+            [named.roll, named.pitch, named.throttle, named.yaw]
+                .into_iter()
+                .chain(named.aux),
+        ) {
+            *output = self.compare(Us::from(Ticks(ticks)));
+        }
+        outputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHANNEL_MAX, CHANNEL_MID};
+
+    #[test]
+    fn test_1mhz_50hz() {
+        let mapper = PwmMapper::new(1_000_000, 50).unwrap();
+        assert_eq!(mapper.period(), 20_000);
+        assert_eq!(mapper.compare(Us(1500)), 1500);
+        assert_eq!(mapper.compare(Us(988)), 988);
+        assert_eq!(mapper.compare(Us(u16::MAX)), 20_000);
+    }
+
+    #[test]
+    fn test_84mhz_400hz() {
+        let mapper = PwmMapper::new(84_000_000, 400).unwrap();
+        assert_eq!(mapper.period(), 210_000);
+        assert_eq!(mapper.compare(Us(1500)), 126_000);
+        assert_eq!(mapper.compare(Us(2012)), 169_008);
+
+        // 1001 µs at 1.5 MHz is 1501.5 ticks, rounded half up
+        let mapper = PwmMapper::new(1_500_000, 50).unwrap();
+        assert_eq!(mapper.compare(Us(1001)), 1502);
+    }
+
+    #[test]
+    fn test_invalid_configurations() {
+        assert_eq!(
+            PwmMapper::new(1_000_000, 500),
+            Err(PwmConfigError::PeriodTooShort {
+                period: 2000,
+                max_compare: 2012
+            })
+        );
+        assert_eq!(PwmMapper::new(0, 50), Err(PwmConfigError::ZeroFrequency));
+        assert_eq!(
+            PwmMapper::new(1_000_000, 0),
+            Err(PwmConfigError::ZeroFrequency)
+        );
+        assert!(PwmMapper::new(1_000_000, 497).is_ok());
+    }
+
+    #[test]
+    fn test_map_packet_by_function() {
+        let mapper = PwmMapper::new(1_000_000, 50).unwrap();
+        let mut packet = SbusPacket {
+            channels: [CHANNEL_MID; CHANNEL_COUNT],
+            ..SbusPacket::default()
+        };
+        packet.channels[0] = 0;
+        packet.channels[4] = CHANNEL_MAX;
+
+        let aetr = mapper.map_packet(&packet, &ChannelAssignment::AETR);
+        assert_eq!(aetr[0], 1000);
+        assert_eq!(aetr[4], 2000);
+        assert_eq!(aetr[1..4], [1500; 3]);
+
+        // Under TAER channel 0 is the throttle, output 2
+        let taer = mapper.map_packet(&packet, &ChannelAssignment::TAER);
+        assert_eq!(taer[2], 1000);
+        assert_eq!(taer[0], 1500);
+        assert_eq!(taer[4], 2000);
+    }
+}
@@ -0,0 +1,202 @@
+//! Conventional channel ranges and their pulse width anchors
+//!
+//! Receivers rarely use the full 11-bit range: FrSky and most others output
+//! [`CHANNEL_MIN`] to [`CHANNEL_STANDARD_MAX`] for full stick travel, which flight
+//! controllers such as Betaflight read as [`US_STANDARD_MIN`] to
+//! [`US_STANDARD_MAX`]. The conversions of [`crate::conversion`] instead map the full
+//! range onto 1000 to 2000 µs. [`ChannelRange`] makes either convention explicit.
+use crate::{packet::SbusPacket, parser::CHANNEL_MAX, scale::scale_linear};
+
+/// Lowest channel value of full stick travel on common receivers
+pub const CHANNEL_MIN: u16 = 172;
+/// Stick center on common receivers
+pub const CHANNEL_CENTER: u16 = 992;
+/// Highest channel value of full stick travel on common receivers
+pub const CHANNEL_STANDARD_MAX: u16 = 1811;
+/// Pulse width of [`CHANNEL_MIN`] in the receiver convention
+pub const US_STANDARD_MIN: u16 = 988;
+/// Pulse width of [`CHANNEL_STANDARD_MAX`] in the receiver convention
+pub const US_STANDARD_MAX: u16 = 2012;
+/// Pulse width of a centered stick in every convention
+pub const US_CENTER: u16 = 1500;
+
+/// Channel value range and the pulse widths its ends stand for
+///
+/// The mapping to pulse widths is linear between `min` and `max`, so the stick
+/// center lands on [`US_CENTER`] only up to rounding, e.g. [`CHANNEL_CENTER`] in
+/// [`ChannelRange::STANDARD`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelRange {
+    pub min: u16,
+    pub max: u16,
+    /// Pulse width of `min`
+    pub us_min: u16,
+    /// Pulse width of `max`
+    pub us_max: u16,
+}
+
+impl ChannelRange {
+    /// Receiver output range, 172 to 1811 for 988 to 2012 µs
+    pub const STANDARD: Self = Self {
+        min: CHANNEL_MIN,
+        max: CHANNEL_STANDARD_MAX,
+        us_min: US_STANDARD_MIN,
+        us_max: US_STANDARD_MAX,
+    };
+
+    /// Full 11-bit range, 0 to 2047 for 1000 to 2000 µs, as used by
+    /// [`crate::ticks_to_us`]
+    pub const FULL: Self = Self {
+        min: 0,
+        max: CHANNEL_MAX,
+        us_min: 1000,
+        us_max: 2000,
+    };
+
+    /// Returns `(min, max)`, the form taken by [`SbusPacket::scale_all_channels`]
+    pub const fn bounds(&self) -> (u16, u16) {
+        (self.min, self.max)
+    }
+
+    /// Converts a channel value to a pulse width, clamping to the range
+    pub const fn to_us(&self, ticks: u16) -> u16 {
+        map(ticks, (self.min, self.max), (self.us_min, self.us_max))
+    }
+
+    /// Converts a pulse width to a channel value, clamping to the range
+    pub const fn from_us(&self, us: u16) -> u16 {
+        map(us, (self.us_min, self.us_max), (self.min, self.max))
+    }
+}
+
+/// Maps `value`, clamped to `from`, linearly onto `to` with rounding
+///
+/// Either range may be reversed. The clamped value maps to a point between `to.0`
+/// and `to.1`, so the result always fits in a `u16`.
+const fn map(value: u16, from: (u16, u16), to: (u16, u16)) -> u16 {
+    let (low, high) = if from.0 <= from.1 {
+        (from.0, from.1)
+    } else {
+        (from.1, from.0)
+    };
+    let value = if value < low {
+        low
+    } else if value > high {
+        high
+    } else {
+        value
+    };
+    scale_linear(value, from, to) as u16
+}
+
+impl SbusPacket {
+    /// Rescales every channel from one convention to another, e.g. receiver output
+    /// onto the full 11-bit range
+    pub fn rescale(&self, from: &ChannelRange, to: &ChannelRange) -> SbusPacket {
+        self.scale_all_channels(from.bounds(), to.bounds())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ticks_to_us, us_to_ticks, CHANNEL_MID};
+
+    #[test]
+    fn test_standard_anchors() {
+        let range = ChannelRange::STANDARD;
+        assert_eq!(range.to_us(CHANNEL_MIN), US_STANDARD_MIN);
+        assert_eq!(range.to_us(CHANNEL_CENTER), US_CENTER);
+        assert_eq!(range.to_us(CHANNEL_STANDARD_MAX), US_STANDARD_MAX);
+        // Outside the range
+        assert_eq!(range.to_us(0), US_STANDARD_MIN);
+        assert_eq!(range.to_us(CHANNEL_MAX), US_STANDARD_MAX);
+
+        assert_eq!(range.from_us(US_STANDARD_MIN), CHANNEL_MIN);
+        assert_eq!(range.from_us(US_CENTER), CHANNEL_CENTER);
+        assert_eq!(range.from_us(US_STANDARD_MAX), CHANNEL_STANDARD_MAX);
+    }
+
+    #[test]
+    fn test_full_matches_conversion() {
+        let range = ChannelRange::FULL;
+        for ticks in 0..=CHANNEL_MAX {
+            assert_eq!(range.to_us(ticks), ticks_to_us(ticks));
+        }
+        for us in 1000..=2000 {
+            assert_eq!(range.from_us(us), us_to_ticks(us));
+        }
+        assert_eq!(range.to_us(CHANNEL_MID), US_CENTER);
+    }
+
+    #[test]
+    fn test_round_trip_within_one_count() {
+        for range in [ChannelRange::STANDARD, ChannelRange::FULL] {
+            for us in range.us_min..=range.us_max {
+                let back = range.to_us(range.from_us(us));
+                assert!(back.abs_diff(us) <= 1, "{} µs came back as {}", us, back);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unusual_ranges() {
+        // Reversed channel travel, and pulse widths beyond CHANNEL_MAX
+        let range = ChannelRange {
+            min: 1811,
+            max: 172,
+            us_min: 750,
+            us_max: 2250,
+        };
+        assert_eq!(range.to_us(1811), 750);
+        assert_eq!(range.to_us(172), 2250);
+        assert_eq!(range.to_us(0), 2250);
+        assert_eq!(range.to_us(CHANNEL_MAX), 750);
+        assert_eq!(range.from_us(2250), 172);
+        assert_eq!(range.from_us(u16::MAX), 172);
+        assert_eq!(range.from_us(0), 1811);
+
+        // Degenerate ranges must not underflow
+        let flat = ChannelRange {
+            min: 992,
+            max: 992,
+            us_min: 1500,
+            us_max: 1500,
+        };
+        assert_eq!(flat.to_us(0), 1500);
+        assert_eq!(flat.from_us(2000), 992);
+    }
+
+    #[test]
+    fn test_rescale() {
+        let packet = SbusPacket {
+            channels: [
+                CHANNEL_MIN,
+                CHANNEL_STANDARD_MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+            ],
+            ..SbusPacket::default()
+        };
+        let full = packet.rescale(&ChannelRange::STANDARD, &ChannelRange::FULL);
+        assert_eq!(full.channels[..3], [0, CHANNEL_MAX, 0]);
+
+        let standard = full.rescale(&ChannelRange::FULL, &ChannelRange::STANDARD);
+        assert_eq!(
+            standard.channels[..3],
+            [CHANNEL_MIN, CHANNEL_STANDARD_MAX, CHANNEL_MIN]
+        );
+    }
+}
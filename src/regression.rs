@@ -0,0 +1,153 @@
+//! Recording and replay of inputs on which the decoders disagree
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::string::String;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::vec::Vec;
+
+use crate::{frame_ref::SbusFrameRef, packet::SbusPacket, CHANNEL_COUNT, SBUS_FRAME_LENGTH};
+
+/// Extension of recorded inputs
+const REGRESSION_EXTENSION: &str = "bin";
+/// Maximum length of the description part of a file name
+const SLUG_MAX_LEN: usize = 40;
+
+/// Saves failing inputs of fuzz and differential harnesses as regression inputs
+///
+/// Each input is written to its own file named after the time of recording and
+/// the description, e.g. `1760630400-000000123-decoders-disagree.bin`, holding the
+/// raw bytes. [`load_regressions`] reads them back for replay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureRecorder {
+    dir: PathBuf,
+}
+
+impl FailureRecorder {
+    /// Creates a recorder writing to `dir`, which is created on first use
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Creates a recorder writing to the crate's `tests/regressions` directory,
+    /// which the `regressions` integration test replays
+    pub fn regressions() -> Self {
+        Self::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/regressions"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Writes `input` to a new file and returns its path
+    pub fn record(&self, input: &[u8], description: &str) -> io::Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let mut slug: String = description
+            .chars()
+            .map(|c| match c {
+                c if c.is_ascii_alphanumeric() => c.to_ascii_lowercase(),
+                _ => '-',
+            })
+            .take(SLUG_MAX_LEN)
+            .collect();
+        if slug.is_empty() {
+            slug.push_str("failure");
+        }
+        let path = self.dir.join(format!(
+            "{}-{:09}-{}.{}",
+            now.as_secs(),
+            now.subsec_nanos(),
+            slug,
+            REGRESSION_EXTENSION
+        ));
+        fs::write(&path, input)?;
+        Ok(path)
+    }
+}
+
+/// Loads every recorded input in `dir`, sorted by file name
+///
+/// A missing directory holds no inputs.
+pub fn load_regressions(dir: impl AsRef<Path>) -> io::Result<Vec<(PathBuf, Vec<u8>)>> {
+    let entries = match fs::read_dir(dir) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        entries => entries?,
+    };
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|ext| ext == REGRESSION_EXTENSION)
+        {
+            let input = fs::read(&path)?;
+            inputs.push((path, input));
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Decodes every frame-sized window of `input` with [`SbusPacket::from_array`]
+/// and channel by channel with [`SbusFrameRef`], returning the offset of the first
+/// window where they disagree
+pub fn check_decoder_agreement(input: &[u8]) -> Result<(), usize> {
+    for (offset, window) in input.windows(SBUS_FRAME_LENGTH).enumerate() {
+        let frame: &[u8; SBUS_FRAME_LENGTH] = window.try_into().expect("window has frame length");
+        let agree = match (SbusPacket::from_array(frame), SbusFrameRef::new(frame)) {
+            (Ok(packet), Ok(frame_ref)) => {
+                (0..CHANNEL_COUNT).all(|i| packet.channels[i] == frame_ref.channel(i))
+                    && packet.flags == frame_ref.flags()
+            }
+            (Err(a), Err(b)) => a == b,
+            _ => false,
+        };
+        if !agree {
+            return Err(offset);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sbus-rs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_record_and_load() {
+        let dir = temp_dir("record");
+        let recorder = FailureRecorder::new(&dir);
+        let first = recorder.record(&[1, 2, 3], "Decoders disagree!").unwrap();
+        let second = recorder.record(&[], "").unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let name = first.file_name().unwrap().to_str().unwrap();
+        assert!(name.ends_with("-decoders-disagree-.bin"), "{}", name);
+        assert!(second.to_str().unwrap().ends_with("-failure.bin"));
+
+        let inputs = load_regressions(&dir).unwrap();
+        assert_eq!(inputs.len(), 2);
+        assert!(inputs.contains(&(first, vec![1, 2, 3])));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(load_regressions(&dir).unwrap(), []);
+    }
+
+    #[test]
+    fn test_decoder_agreement() {
+        let mut input = vec![0x0F, 0x0F];
+        input.extend_from_slice(&SbusPacket::default().to_array());
+        input.extend_from_slice(&[0xFF; 30]);
+        assert_eq!(check_decoder_agreement(&input), Ok(()));
+        assert_eq!(check_decoder_agreement(&[0x0F; 10]), Ok(()));
+    }
+}
@@ -0,0 +1,197 @@
+//! Linear rescaling and trimming of channel values for calibration
+use crate::{packet::SbusPacket, CHANNEL_COUNT, CHANNEL_MAX};
+
+/// Maps `value` linearly from `from` onto `to`, rounding to the nearest value and
+/// clamping to `0..=CHANNEL_MAX`
+///
+/// Either range may be reversed. A degenerate `from` range maps everything to
+/// `to.0`.
+const fn scale_value(value: u16, from: (u16, u16), to: (u16, u16)) -> u16 {
+    clamp(scale_linear(value, from, to))
+}
+
+/// Maps `value` linearly from `from` onto `to`, rounding to the nearest value,
+/// without clamping the result
+///
+/// Either range may be reversed. A degenerate `from` range maps everything to
+/// `to.0`.
+pub(crate) const fn scale_linear(value: u16, from: (u16, u16), to: (u16, u16)) -> i64 {
+    let mut span = from.1 as i64 - from.0 as i64;
+    let mut num = (value as i64 - from.0 as i64) * (to.1 as i64 - to.0 as i64);
+    if span == 0 {
+        return to.0 as i64;
+    }
+    if span < 0 {
+        span = -span;
+        num = -num;
+    }
+    to.0 as i64 + (2 * num + span).div_euclid(2 * span)
+}
+
+const fn clamp(value: i64) -> u16 {
+    if value < 0 {
+        0
+    } else if value > CHANNEL_MAX as i64 {
+        CHANNEL_MAX
+    } else {
+        value as u16
+    }
+}
+
+/// Shifts a channel value by `trim` steps of `trim_scale` raw counts each
+///
+/// Transmitters typically use 4 to 8 counts per trim step. The result saturates at
+/// 0 and [`CHANNEL_MAX`].
+pub const fn channel_at_trim(raw: u16, trim: i16, trim_scale: u16) -> u16 {
+    let offset = (trim as i32).saturating_mul(trim_scale as i32);
+    clamp((raw as i64).saturating_add(offset as i64))
+}
+
+impl SbusPacket {
+    /// Rescales every channel linearly from `from_range` onto `to_range`
+    ///
+    /// Results are clamped to `0..=CHANNEL_MAX`. Flags are kept.
+    pub fn scale_all_channels(&self, from_range: (u16, u16), to_range: (u16, u16)) -> SbusPacket {
+        SbusPacket {
+            channels: self
+                .channels
+                .map(|value| scale_value(value, from_range, to_range)),
+            flags: self.flags,
+        }
+    }
+
+    /// Applies a trim to every channel, see [`channel_at_trim`]
+    ///
+    /// Flags are kept.
+    pub fn with_all_trims(&self, trims: &[i16; CHANNEL_COUNT], trim_scale: u16) -> SbusPacket {
+        let mut channels = self.channels;
+        for (value, &trim) in channels.iter_mut().zip(trims) {
+            *value = channel_at_trim(*value, trim, trim_scale);
+        }
+        SbusPacket {
+            channels,
+            flags: self.flags,
+        }
+    }
+}
+
+/// Per-channel calibration, mapping each channel from its measured range onto a
+/// target range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerChannelScaler {
+    pub from: [(u16, u16); CHANNEL_COUNT],
+    pub to: [(u16, u16); CHANNEL_COUNT],
+}
+
+impl PerChannelScaler {
+    /// Rescales every channel of the packet with its own ranges
+    ///
+    /// Results are clamped to `0..=CHANNEL_MAX`. Flags are kept.
+    pub fn apply(&self, packet: &SbusPacket) -> SbusPacket {
+        let mut channels = packet.channels;
+        for (i, value) in channels.iter_mut().enumerate() {
+            *value = scale_value(*value, self.from[i], self.to[i]);
+        }
+        SbusPacket {
+            channels,
+            flags: packet.flags,
+        }
+    }
+}
+
+impl Default for PerChannelScaler {
+    /// Identity mapping over the full channel range
+    fn default() -> Self {
+        Self {
+            from: [(0, CHANNEL_MAX); CHANNEL_COUNT],
+            to: [(0, CHANNEL_MAX); CHANNEL_COUNT],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Flags, CHANNEL_MID};
+
+    fn packet(values: [u16; 3]) -> SbusPacket {
+        let mut channels = [0; 16];
+        channels[..3].copy_from_slice(&values);
+        SbusPacket {
+            channels,
+            flags: Flags::from_byte(0b1000),
+        }
+    }
+
+    #[test]
+    fn test_scale_endpoints_and_midpoint() {
+        let scaled = packet([172, 992, 1811]).scale_all_channels((172, 1811), (0, CHANNEL_MAX));
+        assert_eq!(scaled.channels[..3], [0, CHANNEL_MID, CHANNEL_MAX]);
+        assert_eq!(scaled.flags, Flags::from_byte(0b1000));
+
+        let scaled =
+            packet([0, CHANNEL_MID, CHANNEL_MAX]).scale_all_channels((0, 2047), (172, 1811));
+        assert_eq!(scaled.channels[..3], [172, 992, 1811]);
+    }
+
+    #[test]
+    fn test_scale_clamps() {
+        let scaled = packet([100, 1900, 0]).scale_all_channels((172, 1811), (0, CHANNEL_MAX));
+        assert_eq!(scaled.channels[..3], [0, CHANNEL_MAX, 0]);
+    }
+
+    #[test]
+    fn test_scale_reversed_and_degenerate() {
+        let scaled = packet([172, 992, 1811]).scale_all_channels((1811, 172), (0, CHANNEL_MAX));
+        assert_eq!(scaled.channels[..3], [CHANNEL_MAX, 1023, 0]);
+
+        let scaled = packet([172, 992, 1811]).scale_all_channels((500, 500), (10, 20));
+        assert_eq!(scaled.channels[..3], [10; 3]);
+    }
+
+    #[test]
+    fn test_per_channel_scaler() {
+        let mut scaler = PerChannelScaler::default();
+        let input = packet([200, 1000, 1800]);
+        assert_eq!(scaler.apply(&input), input);
+
+        scaler.from[0] = (200, 1800);
+        scaler.from[1] = (200, 1800);
+        scaler.to[1] = (1000, 2000);
+        let scaled = scaler.apply(&input);
+        assert_eq!(scaled.channels[0], 0);
+        assert_eq!(scaled.channels[1], 1500);
+        assert_eq!(scaled.channels[2], 1800);
+    }
+
+    #[test]
+    fn test_channel_at_trim() {
+        assert_eq!(channel_at_trim(CHANNEL_MID, 0, 4), CHANNEL_MID);
+        assert_eq!(channel_at_trim(CHANNEL_MID, 10, 4), CHANNEL_MID + 40);
+        assert_eq!(channel_at_trim(CHANNEL_MID, -10, 8), CHANNEL_MID - 80);
+        assert_eq!(
+            channel_at_trim(CHANNEL_MAX, i16::MAX, u16::MAX),
+            CHANNEL_MAX
+        );
+        assert_eq!(channel_at_trim(CHANNEL_MAX, 1, 4), CHANNEL_MAX);
+        assert_eq!(channel_at_trim(0, i16::MIN, u16::MAX), 0);
+        assert_eq!(channel_at_trim(2, -1, 4), 0);
+    }
+
+    #[test]
+    fn test_with_all_trims() {
+        let mut trims = [0i16; CHANNEL_COUNT];
+        trims[0] = 25;
+        trims[1] = -25;
+        trims[2] = i16::MAX;
+        let mut input = packet([CHANNEL_MID; 3]);
+        input.channels[3] = 500;
+
+        let trimmed = input.with_all_trims(&trims, 4);
+        assert_eq!(
+            trimmed.channels[..4],
+            [CHANNEL_MID + 100, CHANNEL_MID - 100, CHANNEL_MAX, 500]
+        );
+        assert_eq!(trimmed.flags, input.flags);
+    }
+}
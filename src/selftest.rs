@@ -0,0 +1,161 @@
+//! Runtime self-test of the SBUS encode/decode path
+use crate::{
+    packet::{Flags, SbusPacket},
+    ranges::{CHANNEL_CENTER, CHANNEL_MIN, CHANNEL_STANDARD_MAX},
+    SbusError, SelfTestFailure, SelfTestFailureKind, CHANNEL_MAX, CHANNEL_MID, SBUS_FRAME_LENGTH,
+};
+
+/// Canonical packets covering the channel range, every bit position and every flag
+const VECTORS: [SbusPacket; 7] = [
+    SbusPacket {
+        channels: [0; 16],
+        flags: Flags::from_byte(0),
+    },
+    SbusPacket {
+        channels: [CHANNEL_MAX; 16],
+        flags: Flags::from_byte(0x0F),
+    },
+    SbusPacket {
+        channels: [CHANNEL_MID; 16],
+        flags: Flags::from_byte(0),
+    },
+    SbusPacket {
+        channels: [
+            0x0555, 0x02AA, 0x0555, 0x02AA, 0x0555, 0x02AA, 0x0555, 0x02AA, 0x0555, 0x02AA, 0x0555,
+            0x02AA, 0x0555, 0x02AA, 0x0555, 0x02AA,
+        ],
+        flags: Flags::from_byte(0b0101),
+    },
+    SbusPacket {
+        channels: [
+            0x02AA, 0x0555, 0x02AA, 0x0555, 0x02AA, 0x0555, 0x02AA, 0x0555, 0x02AA, 0x0555, 0x02AA,
+            0x0555, 0x02AA, 0x0555, 0x02AA, 0x0555,
+        ],
+        flags: Flags::from_byte(0b1010),
+    },
+    SbusPacket {
+        channels: [
+            1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 172, 992, 1811, 2046, 2047,
+        ],
+        flags: Flags::from_byte(0b0100),
+    },
+    // Receiver failsafe output: sticks centered, throttle low, switches at their ends
+    SbusPacket {
+        channels: [
+            CHANNEL_CENTER,
+            CHANNEL_CENTER,
+            CHANNEL_MIN,
+            CHANNEL_CENTER,
+            CHANNEL_STANDARD_MAX,
+            CHANNEL_MIN,
+            CHANNEL_STANDARD_MAX,
+            CHANNEL_MIN,
+            CHANNEL_STANDARD_MAX,
+            CHANNEL_MIN,
+            CHANNEL_STANDARD_MAX,
+            CHANNEL_MIN,
+            CHANNEL_STANDARD_MAX,
+            CHANNEL_MIN,
+            CHANNEL_STANDARD_MAX,
+            CHANNEL_MIN,
+        ],
+        flags: Flags::from_byte(0b1000),
+    },
+];
+
+/// Verifies that canonical packets survive encoding and decoding unchanged
+///
+/// Runs in bounded time and does not allocate, so it can be called during board
+/// bring-up before flight.
+pub fn self_test() -> Result<(), SelfTestFailure> {
+    self_test_via(|frame| frame.try_into().map_err(|_| SbusError::InvalidFormat))
+}
+
+/// Same as [`self_test`], with every encoded frame routed through `transfer`
+///
+/// `transfer` receives the encoded frame and returns the frame that came back, e.g.
+/// after a UART loopback. A transport error is reported as a decode failure of the
+/// current vector.
+pub fn self_test_via<F>(mut transfer: F) -> Result<(), SelfTestFailure>
+where
+    F: FnMut(&[u8]) -> Result<[u8; SBUS_FRAME_LENGTH], SbusError>,
+{
+    for (vector, packet) in VECTORS.iter().enumerate() {
+        let fail = |kind| SelfTestFailure { vector, kind };
+        let frame =
+            transfer(&packet.to_array()).map_err(|e| fail(SelfTestFailureKind::Decode(e)))?;
+        let decoded =
+            SbusPacket::from_array(&frame).map_err(|e| fail(SelfTestFailureKind::Decode(e)))?;
+        if decoded != *packet {
+            return Err(fail(SelfTestFailureKind::Mismatch));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test_passes() {
+        assert_eq!(self_test(), Ok(()));
+    }
+
+    #[test]
+    fn test_vectors_cover_flags_and_bits() {
+        let flag_bits = VECTORS.iter().fold(0, |acc, p| acc | p.flags.to_byte());
+        assert_eq!(flag_bits, 0x0F);
+        for bit in 0..11 {
+            assert!(VECTORS
+                .iter()
+                .any(|p| p.channels.iter().any(|&v| v & (1 << bit) != 0)));
+        }
+    }
+
+    #[test]
+    fn test_self_test_detects_corruption() {
+        // Flipping a single data bit in the third vector
+        let mut count = 0;
+        let result = self_test_via(|frame| {
+            let mut frame: [u8; SBUS_FRAME_LENGTH] = frame.try_into().unwrap();
+            if count == 2 {
+                frame[7] ^= 0x10;
+            }
+            count += 1;
+            Ok(frame)
+        });
+        assert_eq!(
+            result,
+            Err(SelfTestFailure {
+                vector: 2,
+                kind: SelfTestFailureKind::Mismatch,
+            })
+        );
+    }
+
+    #[test]
+    fn test_self_test_reports_decode_errors() {
+        let result = self_test_via(|frame| {
+            let mut frame: [u8; SBUS_FRAME_LENGTH] = frame.try_into().unwrap();
+            frame[SBUS_FRAME_LENGTH - 1] = 0xFF;
+            Ok(frame)
+        });
+        assert_eq!(
+            result,
+            Err(SelfTestFailure {
+                vector: 0,
+                kind: SelfTestFailureKind::Decode(SbusError::InvalidFooter(0xFF)),
+            })
+        );
+
+        let result = self_test_via(|_| Err(SbusError::ReadError));
+        assert_eq!(
+            result,
+            Err(SelfTestFailure {
+                vector: 0,
+                kind: SelfTestFailureKind::Decode(SbusError::ReadError),
+            })
+        );
+    }
+}
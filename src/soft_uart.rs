@@ -0,0 +1,135 @@
+//! Frame conversion to and from the bits of a software UART
+use crate::{packet::SbusPacket, SbusError, SBUS_FRAME_LENGTH};
+
+/// Bits per byte in SBUS 8E2 framing: start bit, 8 data bits, even parity and
+/// 2 stop bits
+pub const SOFT_UART_BITS_PER_BYTE: usize = 12;
+/// Bits of a complete frame in SBUS 8E2 framing
+pub const SOFT_UART_FRAME_BITS: usize = SBUS_FRAME_LENGTH * SOFT_UART_BITS_PER_BYTE;
+
+impl SbusPacket {
+    /// Assembles a frame from sampled UART bits and decodes it
+    ///
+    /// `bits` holds the logical bit values, i.e. the SBUS line level already
+    /// inverted, in transmission order: for each byte a start bit (`false`), the
+    /// data bits LSB first, the even parity bit and two stop bits (`true`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbusError::FramingError` with the byte index if a start or stop bit
+    /// is wrong, `SbusError::ParityError` if the parity bit does not match, and the
+    /// errors of [`SbusPacket::from_array`] otherwise.
+    pub fn from_raw_bits(bits: &[bool; SOFT_UART_FRAME_BITS]) -> Result<SbusPacket, SbusError> {
+        let mut frame = [0u8; SBUS_FRAME_LENGTH];
+        for (index, (byte, bits)) in frame
+            .iter_mut()
+            .zip(bits.chunks_exact(SOFT_UART_BITS_PER_BYTE))
+            .enumerate()
+        {
+            if bits[0] || !bits[10] || !bits[11] {
+                return Err(SbusError::FramingError(index));
+            }
+            *byte = bits[1..9]
+                .iter()
+                .rev()
+                .fold(0, |byte, &bit| byte << 1 | bit as u8);
+            if (byte.count_ones() % 2 == 1) != bits[9] {
+                return Err(SbusError::ParityError(index));
+            }
+        }
+        SbusPacket::from_array(&frame)
+    }
+
+    /// Encodes the frame as UART bits for bit-banged output
+    ///
+    /// Inverse of [`SbusPacket::from_raw_bits`], in the same bit order. The bits are
+    /// logical values; invert them to drive the SBUS line.
+    pub fn to_raw_bits(&self) -> [bool; SOFT_UART_FRAME_BITS] {
+        let mut bits = [false; SOFT_UART_FRAME_BITS];
+        for (byte, bits) in self
+            .to_array()
+            .into_iter()
+            .zip(bits.chunks_exact_mut(SOFT_UART_BITS_PER_BYTE))
+        {
+            for (i, bit) in bits[1..9].iter_mut().enumerate() {
+                *bit = byte >> i & 1 == 1;
+            }
+            bits[9] = byte.count_ones() % 2 == 1;
+            bits[10] = true;
+            bits[11] = true;
+        }
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+
+    fn packet() -> SbusPacket {
+        SbusPacket {
+            channels: core::array::from_fn(|i| 172 + i as u16 * 109),
+            flags: Flags::from_byte(0b0101),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let bits = packet().to_raw_bits();
+        assert_eq!(SbusPacket::from_raw_bits(&bits), Ok(packet()));
+    }
+
+    #[test]
+    fn test_parity_error_reports_byte() {
+        let mut bits = packet().to_raw_bits();
+        // Flip a data bit of byte 7
+        bits[7 * SOFT_UART_BITS_PER_BYTE + 3] ^= true;
+        assert_eq!(
+            SbusPacket::from_raw_bits(&bits),
+            Err(SbusError::ParityError(7))
+        );
+    }
+
+    #[test]
+    fn test_framing_error_reports_byte() {
+        let mut bits = packet().to_raw_bits();
+        bits[24 * SOFT_UART_BITS_PER_BYTE + 11] = false;
+        assert_eq!(
+            SbusPacket::from_raw_bits(&bits),
+            Err(SbusError::FramingError(24))
+        );
+        let mut bits = packet().to_raw_bits();
+        bits[0] = true;
+        assert_eq!(
+            SbusPacket::from_raw_bits(&bits),
+            Err(SbusError::FramingError(0))
+        );
+    }
+
+    #[test]
+    fn test_frame_errors_pass_through() {
+        let mut bits = packet().to_raw_bits();
+        // Turn the footer into 0x04, keeping its parity even
+        bits[24 * SOFT_UART_BITS_PER_BYTE + 3] = true;
+        bits[24 * SOFT_UART_BITS_PER_BYTE + 9] = true;
+        assert_eq!(
+            SbusPacket::from_raw_bits(&bits),
+            Err(SbusError::InvalidFooter(0x04))
+        );
+    }
+
+    #[test]
+    fn test_raw_bits_layout() {
+        let bits = packet().to_raw_bits();
+        // Header 0x0F: start bit, data LSB first, even parity 0, stop bits
+        let header = [
+            false, true, true, true, true, false, false, false, false, false, true, true,
+        ];
+        assert_eq!(bits[..SOFT_UART_BITS_PER_BYTE], header);
+        for byte in bits.chunks_exact(SOFT_UART_BITS_PER_BYTE) {
+            let ones = byte[1..10].iter().filter(|&&b| b).count();
+            assert_eq!(ones % 2, 0);
+        }
+    }
+}
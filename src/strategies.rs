@@ -0,0 +1,61 @@
+//! Proptest strategies for packets, frames and corrupted byte streams
+//!
+//! Channels shrink toward [`CHANNEL_MID`], flags toward none set and noise toward
+//! no garbage at all, so failing cases reduce to the simplest input.
+use core::ops::Range;
+use std::vec::Vec;
+
+use proptest::{array::uniform16, collection::vec, prelude::*};
+
+use crate::{
+    packet::{Flags, SbusPacket},
+    CHANNEL_MAX, CHANNEL_MID, SBUS_FRAME_LENGTH, SBUS_HEADER,
+};
+
+/// Most garbage bytes inserted before each frame of a [`noisy_stream`]
+const MAX_NOISE_RUN: usize = 2 * SBUS_FRAME_LENGTH;
+
+/// Any packet with channels in `0..=CHANNEL_MAX`
+pub fn any_sbus_packet() -> impl Strategy<Value = SbusPacket> {
+    let offsets = -(CHANNEL_MID as i16)..=(CHANNEL_MAX - CHANNEL_MID) as i16;
+    (uniform16(offsets), 0u8..=0x0F).prop_map(|(offsets, flags)| SbusPacket {
+        channels: offsets.map(|offset| CHANNEL_MID.wrapping_add_signed(offset)),
+        flags: Flags::from_byte(flags),
+    })
+}
+
+/// Encoded frame of [`any_sbus_packet`]
+pub fn any_frame_bytes() -> impl Strategy<Value = [u8; SBUS_FRAME_LENGTH]> {
+    any_sbus_packet().prop_map(|packet| packet.to_array())
+}
+
+/// Stream of `frames` frames, each preceded by garbage, with the packets a decoder
+/// is expected to recover in order
+///
+/// Garbage never contains [`SBUS_HEADER`], so it cannot form a frame of its own
+/// and the first header after it is always the start of the next frame.
+pub fn noisy_stream(frames: Range<usize>) -> impl Strategy<Value = (Vec<u8>, Vec<SbusPacket>)> {
+    let noise_byte = (0..u8::MAX).prop_map(|b| if b >= SBUS_HEADER { b + 1 } else { b });
+    vec(
+        (vec(noise_byte, 0..=MAX_NOISE_RUN), any_sbus_packet()),
+        frames,
+    )
+    .prop_map(|parts| {
+        let mut stream = Vec::new();
+        let mut packets = Vec::with_capacity(parts.len());
+        for (noise, packet) in parts {
+            stream.extend_from_slice(&noise);
+            stream.extend_from_slice(&packet.to_array());
+            packets.push(packet);
+        }
+        (stream, packets)
+    })
+}
+
+/// Valid frame with one byte changed
+pub fn corrupted_frame() -> impl Strategy<Value = [u8; SBUS_FRAME_LENGTH]> {
+    (any_frame_bytes(), 0..SBUS_FRAME_LENGTH, 1..=u8::MAX).prop_map(|(mut frame, index, mask)| {
+        frame[index] ^= mask;
+        frame
+    })
+}
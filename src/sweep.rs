@@ -0,0 +1,111 @@
+//! Conformance analysis of channel sweeps passed through a decode pipeline
+use std::vec::Vec;
+
+use crate::packet::SbusPacket;
+
+/// Shape of a swept channel: monotonic segments, step sizes and gaps
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SweepReport {
+    /// Values analyzed
+    pub samples: usize,
+    /// Runs of values moving in one direction; a clean triangle sweep has one per
+    /// half period
+    pub segments: usize,
+    /// Largest absolute change between consecutive values
+    pub max_step: u16,
+    /// Absolute change between each pair of consecutive values
+    pub steps: Vec<u16>,
+}
+
+impl SweepReport {
+    /// Analyzes a sequence of values, e.g. a channel converted to microseconds
+    ///
+    /// Repeated values extend the current segment without changing its direction.
+    pub fn from_values(values: impl IntoIterator<Item = u16>) -> Self {
+        let mut report = SweepReport::default();
+        let mut previous: Option<u16> = None;
+        let mut rising: Option<bool> = None;
+        for value in values {
+            report.samples += 1;
+            let Some(last) = previous.replace(value) else {
+                report.segments = 1;
+                continue;
+            };
+            let step = value.abs_diff(last);
+            report.steps.push(step);
+            report.max_step = report.max_step.max(step);
+            if step == 0 {
+                continue;
+            }
+            let up = value > last;
+            if rising.is_some_and(|rising| rising != up) {
+                report.segments += 1;
+            }
+            rising = Some(up);
+        }
+        report
+    }
+
+    /// Estimates the frames missing from the sweep given the step between two
+    /// consecutive generated frames
+    ///
+    /// Each step spanning `n` nominal steps counts as `n - 1` dropped frames.
+    pub fn estimated_dropped_frames(&self, nominal_step: u16) -> usize {
+        let nominal = u32::from(nominal_step.max(1));
+        self.steps
+            .iter()
+            .map(|&step| ((u32::from(step) + nominal / 2) / nominal).saturating_sub(1) as usize)
+            .sum()
+    }
+}
+
+/// Analyzes `channel` of a sequence of packets generated as a sweep
+///
+/// # Panics
+///
+/// Panics if `channel` is not below 16.
+pub fn verify_sweep(packets: impl Iterator<Item = SbusPacket>, channel: usize) -> SweepReport {
+    SweepReport::from_values(packets.map(|packet| packet.channels[channel]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Flags, FrameGenerator, Pattern, CHANNEL_MAX};
+
+    #[test]
+    fn test_clean_sweep() {
+        let generator = FrameGenerator::new(Pattern::Sweep { period: 64 }, Flags::default());
+        let packets = generator.map(|frame| SbusPacket::from_array(&frame).unwrap());
+        let report = verify_sweep(packets.take(129), 3);
+        assert_eq!(report.samples, 129);
+        // Up, down, up, down over two periods
+        assert_eq!(report.segments, 4);
+        assert_eq!(report.max_step, 64);
+        assert_eq!(report.estimated_dropped_frames(64), 0);
+    }
+
+    #[test]
+    fn test_gaps_and_reversals() {
+        let report = SweepReport::from_values([0, 10, 20, 40, 50, 50, 30, 40]);
+        assert_eq!(report.segments, 3);
+        assert_eq!(report.max_step, 20);
+        assert_eq!(report.steps, [10, 10, 20, 10, 0, 20, 10]);
+        assert_eq!(report.estimated_dropped_frames(10), 2);
+
+        assert_eq!(SweepReport::from_values([]), SweepReport::default());
+        assert_eq!(SweepReport::from_values([CHANNEL_MAX]).segments, 1);
+    }
+
+    #[test]
+    fn test_large_steps() {
+        let report = SweepReport::from_values([0, u16::MAX, 0]);
+        assert_eq!(report.max_step, u16::MAX);
+        assert_eq!(report.estimated_dropped_frames(u16::MAX), 0);
+        assert_eq!(report.estimated_dropped_frames(2), 2 * 32767);
+        assert_eq!(
+            report.estimated_dropped_frames(0),
+            2 * (u16::MAX as usize - 1)
+        );
+    }
+}
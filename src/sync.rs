@@ -0,0 +1,212 @@
+//! Byte-wise frame alignment that confirms a candidate alignment before locking
+use crate::{packet::SbusPacket, SBUS_FRAME_LENGTH};
+
+/// Alignment seen validating at byte offsets `phase` modulo the frame length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Candidate {
+    phase: u8,
+    /// Consecutive frames that validated at this alignment
+    score: u32,
+}
+
+/// Frame aligner for byte streams where noise may look like a frame
+///
+/// Single-frame sync locks onto the first 25 bytes with a valid header and footer,
+/// which noise such as payload-like data rich in `0x0F` bytes produces now and
+/// then. `RobustSync` instead tracks up to two candidate alignments on probation,
+/// each scored by its consecutive valid frames, and locks onto the first that
+/// reaches `probation_frames`. Frames seen during probation are not emitted. Once
+/// locked it emits every frame at the alignment and drops the lock at the first
+/// invalid one, like single-frame sync. With `probation_frames` of 1 it is
+/// single-frame sync.
+///
+/// Frames are assumed to follow each other without gaps, as on a UART receiving
+/// SBUS; a gap ends the lock and starts a new probation.
+#[derive(Debug, Clone)]
+pub struct RobustSync {
+    probation_frames: u32,
+    window: [u8; SBUS_FRAME_LENGTH],
+    /// Bytes received, modulo the frame length once the window is full
+    received: usize,
+    filled: bool,
+    candidates: [Option<Candidate>; 2],
+    locked: Option<u8>,
+}
+
+impl RobustSync {
+    /// Creates an aligner confirming alignments over `probation_frames` frames
+    ///
+    /// A value of 0 is treated as 1.
+    pub const fn new(probation_frames: u32) -> Self {
+        Self {
+            probation_frames: if probation_frames == 0 {
+                1
+            } else {
+                probation_frames
+            },
+            window: [0; SBUS_FRAME_LENGTH],
+            received: 0,
+            filled: false,
+            candidates: [None; 2],
+            locked: None,
+        }
+    }
+
+    /// Returns `true` once an alignment passed probation
+    pub fn is_locked(&self) -> bool {
+        self.locked.is_some()
+    }
+
+    /// Feeds the next byte, returning the packet it completes at the locked alignment
+    pub fn push(&mut self, byte: u8) -> Option<SbusPacket> {
+        self.window.copy_within(1.., 0);
+        self.window[SBUS_FRAME_LENGTH - 1] = byte;
+        self.received += 1;
+        if self.received == SBUS_FRAME_LENGTH {
+            self.received = 0;
+            self.filled = true;
+        }
+        if !self.filled {
+            return None;
+        }
+        let phase = self.received as u8;
+        let packet = SbusPacket::from_array(&self.window).ok();
+
+        match self.locked {
+            Some(locked) if locked != phase => return None,
+            Some(_) if packet.is_some() => return packet,
+            Some(_) => self.locked = None,
+            None => {}
+        }
+        self.score(phase, packet.is_some());
+
+        let confirmed = self
+            .candidates
+            .iter()
+            .flatten()
+            .any(|c| c.phase == phase && c.score >= self.probation_frames);
+        if confirmed {
+            self.locked = Some(phase);
+            self.candidates = [None; 2];
+            return packet;
+        }
+        None
+    }
+
+    /// Updates the candidates after a frame boundary at `phase`
+    fn score(&mut self, phase: u8, valid: bool) {
+        let existing = self
+            .candidates
+            .iter_mut()
+            .find(|c| c.is_some_and(|c| c.phase == phase));
+        if let Some(slot) = existing {
+            *slot = slot.filter(|_| valid).map(|c| Candidate {
+                score: c.score + 1,
+                ..c
+            });
+        } else if valid {
+            // Take a free slot, or evict a candidate that has not repeated yet
+            let slot = self
+                .candidates
+                .iter_mut()
+                .min_by_key(|c| c.map_or(0, |c| c.score));
+            if let Some(slot) = slot.filter(|c| c.is_none_or(|c| c.score <= 1)) {
+                *slot = Some(Candidate { phase, score: 1 });
+            }
+        }
+    }
+
+    /// Drops the lock and all candidates and forgets the received bytes
+    pub fn reset(&mut self) {
+        *self = Self::new(self.probation_frames);
+    }
+}
+
+impl Default for RobustSync {
+    /// Single-frame sync, without probation
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Flags, SBUS_HEADER};
+
+    fn packet(value: u16) -> SbusPacket {
+        SbusPacket {
+            channels: [value; 16],
+            flags: Flags::from_byte(0b0001),
+        }
+    }
+
+    fn run(sync: &mut RobustSync, data: &[u8]) -> Vec<SbusPacket> {
+        data.iter().filter_map(|&b| sync.push(b)).collect()
+    }
+
+    /// Raw frames whose byte 5 is a header, and whose byte 4 is a footer in every
+    /// other frame, so a false alignment starting at byte 5 validates every
+    /// second frame
+    fn adversarial_frame(n: usize) -> [u8; SBUS_FRAME_LENGTH] {
+        let mut frame = [0x55; SBUS_FRAME_LENGTH];
+        frame[0] = SBUS_HEADER;
+        frame[4] = if n % 2 == 0 { 0x00 } else { 0x11 };
+        frame[5] = SBUS_HEADER;
+        frame[23] = n as u8;
+        frame[24] = 0x00;
+        frame
+    }
+
+    #[test]
+    fn test_adversarial_false_alignment() {
+        // Start right at the false alignment of an odd frame
+        let mut data = adversarial_frame(1)[5..].to_vec();
+        let frames: Vec<_> = (2..12).map(adversarial_frame).collect();
+        frames.iter().for_each(|f| data.extend_from_slice(f));
+        let real: Vec<_> = frames
+            .iter()
+            .map(|f| SbusPacket::from_array(f).unwrap())
+            .collect();
+
+        let naive = run(&mut RobustSync::default(), &data);
+        assert!(!real.contains(&naive[0]), "single-frame sync locks wrongly");
+
+        let robust = run(&mut RobustSync::new(2), &data);
+        assert_eq!(robust, real[1..]);
+        let robust = run(&mut RobustSync::new(3), &data);
+        assert_eq!(robust, real[2..]);
+    }
+
+    #[test]
+    fn test_clean_stream_after_probation() {
+        let mut data = vec![0xAA, SBUS_HEADER, 0x00];
+        (0..6).for_each(|i| data.extend_from_slice(&packet(i * 100).to_array()));
+
+        let mut sync = RobustSync::new(3);
+        let packets = run(&mut sync, &data);
+        assert!(sync.is_locked());
+        assert_eq!(
+            packets,
+            [packet(200), packet(300), packet(400), packet(500)]
+        );
+    }
+
+    #[test]
+    fn test_relock_after_gap() {
+        let mut sync = RobustSync::new(2);
+        let mut data = Vec::new();
+        (0..3).for_each(|i| data.extend_from_slice(&packet(i).to_array()));
+        assert_eq!(run(&mut sync, &data).len(), 2);
+
+        // Three stray bytes shift the alignment
+        let mut data = vec![0x01, 0x02, 0x03];
+        (3..7).for_each(|i| data.extend_from_slice(&packet(i).to_array()));
+        let packets = run(&mut sync, &data);
+        assert_eq!(packets, [packet(4), packet(5), packet(6)]);
+
+        sync.reset();
+        assert!(!sync.is_locked());
+        assert_eq!(run(&mut sync, &packet(7).to_array()), []);
+    }
+}
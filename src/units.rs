@@ -0,0 +1,211 @@
+//! Typed channel values that keep raw values and pulse widths apart
+use core::{
+    fmt,
+    ops::{Add, Sub},
+};
+
+use crate::{
+    conversion::{ticks_to_us, us_to_ticks, US_MAX, US_MIN},
+    error::PulseWidthOutOfRange,
+    packet::SbusPacket,
+    ranges::ChannelRange,
+    CHANNEL_MAX,
+};
+
+/// Raw 11-bit channel value as carried in a frame
+///
+/// Addition and subtraction saturate at 0 and [`CHANNEL_MAX`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ticks(pub u16);
+
+/// Servo pulse width in microseconds
+///
+/// Addition and subtraction saturate at the bounds of `u16`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Us(pub u16);
+
+impl From<Ticks> for Us {
+    /// Converts with [`ticks_to_us`]
+    fn from(ticks: Ticks) -> Self {
+        Us(ticks_to_us(ticks.0))
+    }
+}
+
+impl TryFrom<Us> for Ticks {
+    type Error = PulseWidthOutOfRange;
+
+    /// Converts with [`us_to_ticks`], rejecting pulse widths outside
+    /// `US_MIN..=US_MAX` instead of clamping them
+    fn try_from(us: Us) -> Result<Self, Self::Error> {
+        if (US_MIN..=US_MAX).contains(&us.0) {
+            Ok(Ticks(us_to_ticks(us.0)))
+        } else {
+            Err(PulseWidthOutOfRange(us.0))
+        }
+    }
+}
+
+impl Ticks {
+    /// Converts to a pulse width in the convention of `range`, clamping to it
+    ///
+    /// [`Us::from`] instead uses [`ChannelRange::FULL`].
+    pub const fn to_us_in(self, range: &ChannelRange) -> Us {
+        Us(range.to_us(self.0))
+    }
+}
+
+impl Us {
+    /// Converts to a channel value in the convention of `range`, rejecting pulse
+    /// widths outside `range.us_min..=range.us_max` instead of clamping them
+    ///
+    /// [`Ticks::try_from`] instead uses [`ChannelRange::FULL`].
+    pub const fn to_ticks_in(self, range: &ChannelRange) -> Result<Ticks, PulseWidthOutOfRange> {
+        let (low, high) = if range.us_min <= range.us_max {
+            (range.us_min, range.us_max)
+        } else {
+            (range.us_max, range.us_min)
+        };
+        if self.0 < low || self.0 > high {
+            Err(PulseWidthOutOfRange(self.0))
+        } else {
+            Ok(Ticks(range.from_us(self.0)))
+        }
+    }
+}
+
+impl Add for Ticks {
+    type Output = Ticks;
+
+    fn add(self, rhs: Ticks) -> Ticks {
+        Ticks(self.0.saturating_add(rhs.0).min(CHANNEL_MAX))
+    }
+}
+
+impl Sub for Ticks {
+    type Output = Ticks;
+
+    fn sub(self, rhs: Ticks) -> Ticks {
+        Ticks(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Add for Us {
+    type Output = Us;
+
+    fn add(self, rhs: Us) -> Us {
+        Us(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for Us {
+    type Output = Us;
+
+    fn sub(self, rhs: Us) -> Us {
+        Us(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl fmt::Display for Ticks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Us {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} µs", self.0)
+    }
+}
+
+impl SbusPacket {
+    /// Returns the raw value of channel `idx`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not below `CHANNEL_COUNT`.
+    pub fn channel_ticks(&self, idx: usize) -> Ticks {
+        Ticks(self.channels[idx])
+    }
+
+    /// Returns the pulse width of channel `idx`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not below `CHANNEL_COUNT`.
+    pub fn channel_us(&self, idx: usize) -> Us {
+        self.channel_ticks(idx).into()
+    }
+
+    /// Returns the pulse width of channel `idx` in the convention of `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not below `CHANNEL_COUNT`.
+    pub fn channel_us_in(&self, idx: usize, range: &ChannelRange) -> Us {
+        self.channel_ticks(idx).to_us_in(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHANNEL_MID;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Us::from(Ticks(0)), Us(US_MIN));
+        assert_eq!(Us::from(Ticks(CHANNEL_MID)), Us(1500));
+        assert_eq!(Us::from(Ticks(CHANNEL_MAX)), Us(US_MAX));
+
+        assert_eq!(Ticks::try_from(Us(1500)), Ok(Ticks(CHANNEL_MID)));
+        assert_eq!(Ticks::try_from(Us(US_MAX)), Ok(Ticks(CHANNEL_MAX)));
+        assert_eq!(Ticks::try_from(Us(999)), Err(PulseWidthOutOfRange(999)));
+        assert_eq!(Ticks::try_from(Us(2001)), Err(PulseWidthOutOfRange(2001)));
+    }
+
+    #[test]
+    fn test_range_conversions() {
+        let range = ChannelRange::STANDARD;
+        assert_eq!(Ticks(172).to_us_in(&range), Us(988));
+        assert_eq!(Ticks(0).to_us_in(&range), Us(988));
+        assert_eq!(Ticks(1811).to_us_in(&range), Us(2012));
+
+        assert_eq!(Us(2012).to_ticks_in(&range), Ok(Ticks(1811)));
+        assert_eq!(Us(1500).to_ticks_in(&range), Ok(Ticks(992)));
+        assert_eq!(Us(987).to_ticks_in(&range), Err(PulseWidthOutOfRange(987)));
+        assert_eq!(
+            Us(2013).to_ticks_in(&range),
+            Err(PulseWidthOutOfRange(2013))
+        );
+
+        let mut packet = SbusPacket::default();
+        packet.channels[1] = 1811;
+        assert_eq!(packet.channel_us_in(1, &range), Us(2012));
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        assert_eq!(Ticks(1000) + Ticks(24), Ticks(CHANNEL_MID));
+        assert_eq!(Ticks(2000) + Ticks(100), Ticks(CHANNEL_MAX));
+        assert_eq!(Ticks(10) - Ticks(20), Ticks(0));
+        assert_eq!(Us(1500) + Us(100), Us(1600));
+        assert_eq!(Us(u16::MAX) + Us(1), Us(u16::MAX));
+        assert_eq!(Us(100) - Us(200), Us(0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Ticks(992).to_string(), "992");
+        assert_eq!(Us(1500).to_string(), "1500 µs");
+    }
+
+    #[test]
+    fn test_typed_accessors() {
+        let mut packet = SbusPacket::default();
+        packet.channels[3] = CHANNEL_MID;
+        assert_eq!(packet.channel_ticks(3), Ticks(CHANNEL_MID));
+        assert_eq!(packet.channel_us(3), Us(1500));
+        assert_eq!(packet.channel_us(0), Us(US_MIN));
+        assert_eq!(core::mem::size_of::<Us>(), core::mem::size_of::<u16>());
+    }
+}
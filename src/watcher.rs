@@ -0,0 +1,160 @@
+//! Threshold-based channel change detection
+use crate::{channel::ChannelId, packet::SbusPacket};
+
+/// A watched channel moved by more than its threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelChange {
+    pub channel: ChannelId,
+    /// Last reported value
+    pub old: u16,
+    pub new: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Watch {
+    channel: ChannelId,
+    threshold: u16,
+    last: Option<u16>,
+}
+
+/// Reports movements of up to `N` watched channels
+///
+/// Each watch compares the channel against the value it last reported, so slow
+/// drifts are reported once they accumulate beyond the threshold. The first
+/// [`ChannelWatcher::update`] only records the baseline.
+#[derive(Debug, Clone, Default)]
+pub struct ChannelWatcher<const N: usize> {
+    watches: heapless::Vec<Watch, N>,
+}
+
+impl<const N: usize> ChannelWatcher<N> {
+    pub const fn new() -> Self {
+        Self {
+            watches: heapless::Vec::new(),
+        }
+    }
+
+    /// Watches `channel`, reporting moves strictly greater than `threshold`
+    ///
+    /// Returns the arguments back if all `N` slots are in use.
+    pub fn watch(&mut self, channel: ChannelId, threshold: u16) -> Result<(), (ChannelId, u16)> {
+        self.watches
+            .push(Watch {
+                channel,
+                threshold,
+                last: None,
+            })
+            .map_err(|watch| (watch.channel, watch.threshold))
+    }
+
+    /// Feeds a packet and returns the watched channels that moved beyond their
+    /// threshold since they were last reported
+    ///
+    /// The watcher state is updated immediately, whether or not the returned
+    /// iterator is consumed.
+    pub fn update(&mut self, packet: &SbusPacket) -> impl Iterator<Item = ChannelChange> {
+        let mut changes = heapless::Vec::<ChannelChange, N>::new();
+        for watch in self.watches.iter_mut() {
+            let new = packet[watch.channel];
+            match watch.last {
+                Some(old) if old.abs_diff(new) > watch.threshold => {
+                    // Cannot fail, there is at most one change per watch
+                    let _ = changes.push(ChannelChange {
+                        channel: watch.channel,
+                        old,
+                        new,
+                    });
+                    watch.last = Some(new);
+                }
+                Some(_) => {}
+                None => watch.last = Some(new),
+            }
+        }
+        changes.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ch(index: usize) -> ChannelId {
+        ChannelId::new(index).unwrap()
+    }
+
+    fn packet(values: &[(usize, u16)]) -> SbusPacket {
+        let mut packet = SbusPacket {
+            channels: [1000; 16],
+            ..SbusPacket::default()
+        };
+        values.iter().for_each(|&(i, v)| packet.channels[i] = v);
+        packet
+    }
+
+    #[test]
+    fn test_first_update_is_baseline() {
+        let mut watcher = ChannelWatcher::<2>::new();
+        watcher.watch(ch(4), 10).unwrap();
+        assert_eq!(watcher.update(&packet(&[(4, 1811)])).count(), 0);
+        assert_eq!(watcher.update(&packet(&[(4, 1811)])).count(), 0);
+    }
+
+    #[test]
+    fn test_threshold_edges() {
+        let mut watcher = ChannelWatcher::<1>::new();
+        watcher.watch(ch(0), 10).unwrap();
+        assert_eq!(watcher.update(&packet(&[])).count(), 0);
+
+        assert_eq!(watcher.update(&packet(&[(0, 1010)])).count(), 0);
+        assert_eq!(watcher.update(&packet(&[(0, 990)])).count(), 0);
+
+        let changes: heapless::Vec<_, 1> = watcher.update(&packet(&[(0, 1011)])).collect();
+        assert_eq!(
+            changes[..],
+            [ChannelChange {
+                channel: ch(0),
+                old: 1000,
+                new: 1011
+            }]
+        );
+
+        // The reported value becomes the new reference
+        assert_eq!(watcher.update(&packet(&[(0, 1001)])).count(), 0);
+        assert_eq!(watcher.update(&packet(&[(0, 1000)])).count(), 1);
+    }
+
+    #[test]
+    fn test_multiple_changes() {
+        let mut watcher = ChannelWatcher::<3>::new();
+        watcher.watch(ch(4), 100).unwrap();
+        watcher.watch(ch(5), 100).unwrap();
+        watcher.watch(ch(6), 100).unwrap();
+        assert_eq!(watcher.update(&packet(&[])).count(), 0);
+
+        let changes: heapless::Vec<_, 3> = watcher
+            .update(&packet(&[(4, 172), (5, 1050), (6, 1811), (7, 1811)]))
+            .collect();
+        assert_eq!(
+            changes[..],
+            [
+                ChannelChange {
+                    channel: ch(4),
+                    old: 1000,
+                    new: 172
+                },
+                ChannelChange {
+                    channel: ch(6),
+                    old: 1000,
+                    new: 1811
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut watcher = ChannelWatcher::<1>::new();
+        assert_eq!(watcher.watch(ch(0), 5), Ok(()));
+        assert_eq!(watcher.watch(ch(1), 7), Err((ch(1), 7)));
+    }
+}
@@ -25,6 +25,15 @@ impl FuzzedSbusFrame {
 fuzz_target!(|frame: FuzzedSbusFrame| {
     let buffer = frame.to_buffer();
     let _ = SbusPacket::from_array(&buffer);
+
+    #[cfg(feature = "std")]
+    if let Err(offset) = sbus_rs::check_decoder_agreement(&buffer) {
+        let path = sbus_rs::FailureRecorder::regressions().record(&buffer, "decoders disagree");
+        panic!(
+            "decoders disagree at offset {}, input saved to {:?}",
+            offset, path
+        );
+    }
 });
 
 use proptest::prelude::*;
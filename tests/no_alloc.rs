@@ -0,0 +1,46 @@
+//! Parser tests restricted to `core` and `heapless`, mirroring how the crate is used
+//! on targets without an allocator
+#![no_std]
+
+use heapless::Vec;
+use sbus_rs::*;
+
+/// Room for a few frames plus noise
+const CAPACITY: usize = 8 * SBUS_FRAME_LENGTH;
+
+fn frame(value: u16) -> [u8; SBUS_FRAME_LENGTH] {
+    SbusPacket {
+        channels: [value; CHANNEL_COUNT],
+        flags: Flags::from_byte(0b1000),
+    }
+    .to_array()
+}
+
+fn capture(values: &[u16]) -> Vec<u8, CAPACITY> {
+    let mut buffer = Vec::new();
+    for &value in values {
+        buffer.extend_from_slice(&frame(value)).unwrap();
+    }
+    buffer
+}
+
+#[test]
+fn test_read_frames_from_slice() {
+    let data = capture(&[172, 992, 1811]);
+    let mut parser = SbusParser::new(data.as_slice());
+
+    for value in [172, 992, 1811] {
+        let packet = parser.read_frame().unwrap();
+        assert_eq!(packet.channels, [value; CHANNEL_COUNT]);
+        assert!(packet.flags.failsafe);
+    }
+    assert_eq!(parser.read_frame(), Err(SbusError::ReadError));
+}
+
+#[test]
+fn test_invalid_header_from_slice() {
+    let mut data = capture(&[992]);
+    data[0] = 0xFF;
+    let mut parser = SbusParser::new(data.as_slice());
+    assert_eq!(parser.read_frame(), Err(SbusError::InvalidHeader(0xFF)));
+}
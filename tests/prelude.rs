@@ -0,0 +1,40 @@
+//! The prelude alone must be enough to build the documented examples
+use sbus_rs::prelude::*;
+
+#[test]
+fn test_prelude_blocking_example() {
+    use embedded_io_adapters::std::FromStd;
+    use std::io::Cursor;
+
+    let mut channels = [CHANNEL_MID; CHANNEL_COUNT];
+    channels[0] = CHANNEL_MAX;
+    let packet = SbusPacket {
+        channels,
+        flags: Flags {
+            failsafe: true,
+            ..Flags::default()
+        },
+    };
+    let frame: [u8; SBUS_FRAME_LENGTH] = packet.to_array();
+    assert_eq!(frame[0], SBUS_HEADER);
+    assert_eq!(frame[SBUS_FRAME_LENGTH - 1], SBUS_FOOTER);
+
+    let mut parser = SbusParser::new(FromStd::new(Cursor::new(frame)));
+    let decoded: Result<SbusPacket, SbusError> = parser.read_frame();
+    let decoded = decoded.expect("Should be a valid frame");
+    assert_eq!(decoded, packet);
+
+    let throttle: ChannelId = ChannelAssignment::AETR.throttle;
+    assert_eq!(decoded[throttle], CHANNEL_MID);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_prelude_async_example() {
+    use embedded_io_adapters::tokio_1::FromTokio;
+    use std::io::Cursor;
+
+    let frame = SbusPacket::default().to_array();
+    let mut parser = SbusParserAsync::new(FromTokio::new(Cursor::new(frame)));
+    assert_eq!(parser.read_frame().await, Ok(SbusPacket::default()));
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "std")]
+use sbus_rs::{check_decoder_agreement, load_regressions, FailureRecorder};
+
+#[test]
+fn test_recorded_inputs_decode_consistently() {
+    let inputs = load_regressions(FailureRecorder::regressions().dir()).unwrap();
+    assert!(!inputs.is_empty());
+    for (path, input) in inputs {
+        assert_eq!(
+            check_decoder_agreement(&input),
+            Ok(()),
+            "decoders disagree on {}",
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "proptest")]
+use std::io::Cursor;
+
+use proptest::prelude::*;
+use sbus_rs::{
+    any_frame_bytes, any_sbus_packet, corrupted_frame, noisy_stream, pack_channels, scan_reader,
+    unpack_channels, SbusPacket, CHANNEL_MAX, SBUS_FOOTER, SBUS_FRAME_LENGTH, SBUS_HEADER,
+};
+
+proptest! {
+    #[test]
+    fn test_packet_round_trip(packet in any_sbus_packet()) {
+        prop_assert!(packet.channels_in_range(0, CHANNEL_MAX));
+        prop_assert_eq!(SbusPacket::from_array(&packet.to_array()), Ok(packet));
+    }
+
+    #[test]
+    fn test_channel_packing_round_trip(packet in any_sbus_packet()) {
+        let mut buffer = [0u8; SBUS_FRAME_LENGTH];
+        pack_channels(&mut buffer, &packet.channels);
+        prop_assert_eq!(unpack_channels(&buffer), packet.channels);
+    }
+
+    #[test]
+    fn test_frame_bytes_are_valid(frame in any_frame_bytes()) {
+        prop_assert_eq!(frame[0], SBUS_HEADER);
+        prop_assert_eq!(frame[SBUS_FRAME_LENGTH - 1], SBUS_FOOTER);
+        prop_assert_eq!(SbusPacket::from_array(&frame).unwrap().to_array(), frame);
+    }
+
+    #[test]
+    fn test_corrupted_frame_validation(frame in corrupted_frame()) {
+        let intact = frame[0] == SBUS_HEADER && frame[SBUS_FRAME_LENGTH - 1] == SBUS_FOOTER;
+        prop_assert_eq!(SbusPacket::from_array(&frame).is_ok(), intact);
+    }
+
+    #[test]
+    fn test_scan_resyncs_on_noisy_stream((stream, expected) in noisy_stream(0..20)) {
+        let packets: Vec<SbusPacket> = scan_reader(Cursor::new(stream))
+            .map(|(_, packet)| packet.unwrap())
+            .collect();
+        prop_assert_eq!(packets, expected);
+    }
+}
@@ -34,8 +34,5 @@ fn test_partial_frame_reading() {
 }
 
 fn create_valid_frame() -> [u8; SBUS_FRAME_LENGTH] {
-    let mut frame = [0u8; SBUS_FRAME_LENGTH];
-    frame[0] = SBUS_HEADER;
-    frame[SBUS_FRAME_LENGTH - 1] = SBUS_FOOTER;
-    frame
+    sbus_frame!([0; CHANNEL_COUNT], 0)
 }
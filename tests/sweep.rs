@@ -0,0 +1,42 @@
+#![cfg(feature = "test-util")]
+use std::io::Cursor;
+
+use sbus_rs::{
+    scan_reader, ticks_to_us, BitFlips, Corruptor, Flags, FrameGenerator, Pattern, SweepReport,
+};
+
+const PERIOD: u32 = 64;
+const FRAMES: usize = 2000;
+
+#[test]
+fn test_sweep_through_lossy_pipeline() {
+    let mut corruptor = BitFlips { period: 100 };
+    let mut stream = Vec::new();
+    let generator = FrameGenerator::new(Pattern::Sweep { period: PERIOD }, Flags::default());
+    for (index, frame) in generator.take(FRAMES).enumerate() {
+        let mut frame = frame.to_vec();
+        corruptor.corrupt(index, &mut frame);
+        stream.extend_from_slice(&frame);
+    }
+
+    let values = scan_reader(Cursor::new(stream))
+        .map(|(_, packet)| ticks_to_us(packet.unwrap().channels[7]));
+    let report = SweepReport::from_values(values);
+
+    // 1% of the frames are corrupted: each one is either dropped or decoded with a
+    // wrong value, which adds at most two reversals
+    let corrupted = FRAMES / 100;
+    let half_periods = FRAMES / (PERIOD as usize / 2);
+    assert!(report.samples >= FRAMES - corrupted);
+    assert!(report.segments >= half_periods);
+    assert!(report.segments <= half_periods + 2 * corrupted);
+    // 1000 µs over half a period is 31.25 µs per frame
+    let nominal_step = 31;
+    assert!(report.estimated_dropped_frames(nominal_step) <= 2 * corrupted);
+    let clean_steps = report
+        .steps
+        .iter()
+        .filter(|&&step| step.abs_diff(nominal_step) <= 1)
+        .count();
+    assert!(clean_steps >= FRAMES - 4 * corrupted);
+}